@@ -0,0 +1,111 @@
+use std::io::Write;
+
+use crate::config::Config;
+use crate::metrics::MetricsCollector;
+use crate::ui::tabs::Tab;
+
+/// Consecutive refreshes over threshold before an alert fires.
+const BREACH_STREAK: u32 = 3;
+
+struct Threshold {
+    tab: Tab,
+    limit: Option<f64>,
+    streak: u32,
+    firing: bool,
+    acknowledged: bool,
+}
+
+impl Threshold {
+    fn new(tab: Tab, limit: Option<f64>) -> Self {
+        Self {
+            tab,
+            limit,
+            streak: 0,
+            firing: false,
+            acknowledged: false,
+        }
+    }
+
+    /// Feed a new sample. Returns true when the alert transitions to firing.
+    fn check(&mut self, value: f64) -> bool {
+        let Some(limit) = self.limit else {
+            return false;
+        };
+
+        if value > limit {
+            self.streak = self.streak.saturating_add(1);
+        } else {
+            // Recovered: clear everything so the next breach alerts again
+            self.streak = 0;
+            self.firing = false;
+            self.acknowledged = false;
+            return false;
+        }
+
+        if !self.firing && self.streak >= BREACH_STREAK {
+            self.firing = true;
+            return true;
+        }
+        false
+    }
+}
+
+/// Threshold alerts for `--alert-cpu`, `--alert-mem` and `--alert-temp`.
+pub struct Alerts {
+    cpu: Threshold,
+    memory: Threshold,
+    temperature: Threshold,
+    bell: bool,
+}
+
+impl Alerts {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            cpu: Threshold::new(Tab::Cpu, config.alert_cpu),
+            memory: Threshold::new(Tab::Memory, config.alert_mem),
+            temperature: Threshold::new(Tab::Temperatures, config.alert_temp),
+            bell: config.alert_bell,
+        }
+    }
+
+    pub fn watches_temperature(&self) -> bool {
+        self.temperature.limit.is_some()
+    }
+
+    pub fn update(&mut self, metrics: &MetricsCollector) {
+        let max_temp = metrics
+            .temperature
+            .sensors
+            .iter()
+            .map(|s| s.temperature)
+            .fold(0.0_f64, f64::max);
+
+        let mut fired = self.cpu.check(metrics.cpu.aggregate_usage);
+        fired |= self.memory.check(metrics.memory.ram_percent);
+        fired |= self.temperature.check(max_temp);
+
+        if fired && self.bell {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07");
+            let _ = stdout.flush();
+        }
+    }
+
+    /// Viewing a tab acknowledges its alert until the metric recovers.
+    pub fn acknowledge(&mut self, tab: Tab) {
+        for t in [&mut self.cpu, &mut self.memory, &mut self.temperature] {
+            if t.tab == tab && t.firing {
+                t.acknowledged = true;
+            }
+        }
+    }
+
+    /// Tabs with an unacknowledged alert, for highlighting in the tab bar.
+    pub fn flagged_tabs(&self) -> Vec<Tab> {
+        [&self.cpu, &self.memory, &self.temperature]
+            .into_iter()
+            .filter(|t| t.firing && !t.acknowledged)
+            .map(|t| t.tab)
+            .collect()
+    }
+}
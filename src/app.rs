@@ -1,12 +1,26 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+use std::collections::HashSet;
 use std::time::{Duration, Instant};
 
+use crate::alert::Alerts;
+use crate::config::Config;
 use crate::event::{self, AppEvent};
 use crate::logs::stream::LogStream;
-use crate::metrics::ai::ChatMessage;
-use crate::metrics::process::ProcessSortField;
+use crate::metrics::ai::{ChatMessage, ChatStatus};
+use crate::metrics::cpu::CpuScale;
+use crate::metrics::disk::DiskSortField;
+use crate::metrics::process::{send_signal, set_priority, ProcessColumn, ProcessSortField, Signal};
+use crate::metrics::temperature::TempUnit;
 use crate::metrics::MetricsCollector;
+use crate::record::Recorder;
+use crate::state::State;
+use crate::ui::layout::compute_layout;
+use crate::ui::tabs::dashboard::DashboardDensity;
+use crate::ui::tabs::processes::{self, max_col_offset, BOTTOM_PANEL_HEIGHT};
 use crate::ui::tabs::Tab;
+use crate::ui::RenderOptions;
+use crate::util::{local_stamp, now_secs, UnitMode};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum AiInputMode {
@@ -15,6 +29,69 @@ pub enum AiInputMode {
     SearchInput,
 }
 
+/// With no input for this long the refresh rate drops to save power.
+const IDLE_AFTER: Duration = Duration::from_secs(120);
+
+/// Refresh interval while idle (unless the configured rate is already slower).
+const IDLE_REFRESH_RATE: Duration = Duration::from_secs(5);
+
+/// Characters moved per Left/Right press when log lines aren't wrapped.
+const LOG_HSCROLL_STEP: usize = 8;
+
+/// How long a toast stays on screen.
+pub const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Named refresh rates that `*` cycles through.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RefreshPreset {
+    Fast,
+    Normal,
+    Slow,
+}
+
+impl RefreshPreset {
+    pub fn rate(self) -> Duration {
+        match self {
+            RefreshPreset::Fast => Duration::from_millis(250),
+            RefreshPreset::Normal => Duration::from_secs(1),
+            RefreshPreset::Slow => Duration::from_secs(5),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RefreshPreset::Fast => "fast",
+            RefreshPreset::Normal => "normal",
+            RefreshPreset::Slow => "slow",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            RefreshPreset::Fast => RefreshPreset::Normal,
+            RefreshPreset::Normal => RefreshPreset::Slow,
+            RefreshPreset::Slow => RefreshPreset::Fast,
+        }
+    }
+
+    /// The preset matching `rate` exactly, if any (`+`/`-` can step off them).
+    pub fn from_rate(rate: Duration) -> Option<Self> {
+        [
+            RefreshPreset::Fast,
+            RefreshPreset::Normal,
+            RefreshPreset::Slow,
+        ]
+        .into_iter()
+        .find(|p| p.rate() == rate)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ToastLevel {
+    Info,
+    Error,
+}
+
 pub struct App {
     pub running: bool,
     pub current_tab: Tab,
@@ -22,45 +99,134 @@ pub struct App {
     pub log_stream: LogStream,
     pub hostname: String,
     pub refresh_rate: Duration,
+    last_input: Instant,
     pub scroll_offset: usize,
     pub filter_mode: bool,
     pub filter_buffer: String,
+    pub search_mode: bool,
+    pub goto_mode: bool,
     pub viewport_height: usize,
     pub process_selected: usize,
+    pub log_selected: usize,
+    /// Detail tabs show only their main panel
+    pub zoomed: bool,
+    /// Detail tabs showing a line chart instead of sparklines
+    pub line_chart_tabs: HashSet<Tab>,
+    /// Wrap long log lines; when off they're truncated and scroll sideways
+    pub log_wrap: bool,
+    /// Characters scrolled off the left of each log line when not wrapping
+    pub log_hscroll: usize,
+    pub followed_pid: Option<u32>,
+    pub col_offset: usize,
+    /// Process table header cells as last drawn, for click-to-sort
+    process_header: Vec<(Rect, ProcessColumn)>,
     pub confirm_kill: Option<(u32, String)>,
+    pub confirm_quit: bool,
+    /// Full header and footer; off leaves a one-line status for more body
+    pub show_chrome: bool,
+    /// Tab/Shift+Tab wrap around at the first and last tab
+    wrap_tabs: bool,
+    pub confirm_renice: Option<(u32, String, i32)>,
+    /// Signal menu open for a process: PID, name, highlighted `Signal::ALL` index
+    pub signal_menu: Option<(u32, String, usize)>,
+    pub confirm_signal: Option<(u32, String, Signal)>,
+    always_confirm_quit: bool,
+    /// Process names killed from `K` without the confirmation overlay.
+    quick_kill: Vec<String>,
     pub show_help: bool,
+    pub help_scroll: usize,
+    help_max_scroll: usize,
+    pub inspect_pid: Option<u32>,
     pub ai_input_mode: AiInputMode,
     pub ai_input_buffer: String,
     pub ai_confirm_delete: Option<String>,
     pub ai_chat_scroll: usize,
-    pub copy_feedback: Option<Instant>,
+    pub toasts: Vec<(String, Instant, ToastLevel)>,
+    pub dashboard_density: DashboardDensity,
+    pub temp_unit: TempUnit,
+    pub render_options: RenderOptions,
+    recorder: Option<Recorder>,
+    pub alerts: Alerts,
 }
 
 impl App {
-    pub fn new(refresh_rate_ms: u64) -> Self {
-        let hostname = hostname::get()
-            .map(|h| h.to_string_lossy().to_string())
-            .unwrap_or_else(|_| "unknown".to_string());
+    pub fn new(config: &Config, recorder: Option<Recorder>) -> Self {
+        let hostname = crate::util::hostname();
+
+        let alerts = Alerts::new(config);
+        let mut metrics = MetricsCollector::new();
+        metrics.watch_temperatures = alerts.watches_temperature();
+        metrics.recording = recorder.is_some();
+        metrics.cpu.scale = config.cpu_scale;
+        if let Some(alpha) = config.smooth_cpu {
+            metrics.cpu.smooth = true;
+            metrics.cpu.smoothing_alpha = alpha;
+        }
+        metrics.process_interval = Duration::from_millis(config.process_interval);
+        metrics.network.show_all = config.all_interfaces;
+        metrics.disk.full_threshold = config.disk_full;
+        metrics.processes.columns = config.columns.clone();
+        metrics.ai.max_context_messages = config.chat_context as usize;
+        metrics.ai.keep_alive = config.keep_alive.clone();
+        metrics.ai.ollama_host = config.ollama_host.clone();
 
         Self {
             running: true,
-            current_tab: Tab::Dashboard,
-            metrics: MetricsCollector::new(),
-            log_stream: LogStream::new(),
+            current_tab: config
+                .tab
+                .or_else(|| State::load().last_tab())
+                .unwrap_or(Tab::Dashboard),
+            metrics,
+            log_stream: LogStream::new(config.log_buffer as usize),
             hostname,
-            refresh_rate: Duration::from_millis(refresh_rate_ms),
+            refresh_rate: Duration::from_millis(config.refresh_rate),
+            last_input: Instant::now(),
             scroll_offset: 0,
             filter_mode: false,
             filter_buffer: String::new(),
+            search_mode: false,
+            goto_mode: false,
             viewport_height: 24,
             process_selected: 0,
+            log_selected: 0,
+            zoomed: false,
+            line_chart_tabs: HashSet::new(),
+            log_wrap: true,
+            log_hscroll: 0,
+            followed_pid: None,
+            col_offset: 0,
+            process_header: Vec::new(),
             confirm_kill: None,
+            confirm_quit: false,
+            show_chrome: true,
+            wrap_tabs: config.wrap_tabs,
+            confirm_renice: None,
+            signal_menu: None,
+            confirm_signal: None,
+            always_confirm_quit: config.confirm_quit,
+            quick_kill: config.quick_kill.clone(),
             show_help: false,
+            help_scroll: 0,
+            help_max_scroll: 0,
+            inspect_pid: None,
             ai_input_mode: AiInputMode::Normal,
             ai_input_buffer: String::new(),
             ai_confirm_delete: None,
             ai_chat_scroll: 0,
-            copy_feedback: None,
+            toasts: Vec::new(),
+            temp_unit: config.temp_unit,
+            render_options: RenderOptions {
+                hi_res: config.hi_res,
+                swap_downsample: false,
+                palette: config.palette,
+            },
+            dashboard_density: if config.compact {
+                DashboardDensity::Compact
+            } else {
+                DashboardDensity::Normal
+            },
+            recorder,
+            alerts,
         }
     }
 
@@ -75,53 +241,186 @@ impl App {
         self.metrics.refresh(Tab::Dashboard);
 
         while self.running {
+            self.sync_followed_process();
+
             // Render
             terminal.draw(|frame| {
                 let area = frame.area();
-                // body = total height - 2 (header) - 1 (footer) - 2 (borders)
-                self.viewport_height = area.height.saturating_sub(5) as usize;
+                // body height - 2 (borders)
+                let body = compute_layout(area, self.show_chrome, self.footer_visible()).body;
+                self.viewport_height = body.height.saturating_sub(2) as usize;
+                if self.current_tab == Tab::Processes && !self.zoomed {
+                    self.viewport_height = self
+                        .viewport_height
+                        .saturating_sub(BOTTOM_PANEL_HEIGHT as usize);
+                }
+                self.help_max_scroll = crate::ui::help::max_scroll(self.current_tab, area);
+                if self.current_tab == Tab::Processes {
+                    self.process_header = processes::header_cells(
+                        body,
+                        &self.metrics.processes.table_columns(),
+                        self.col_offset,
+                        self.zoomed,
+                    );
+                }
                 crate::ui::render(frame, self);
             })?;
 
             // Poll events
+            let selected_before = (self.current_tab, self.process_selected);
             match event::poll_event(poll_timeout)? {
                 AppEvent::Key(key) => self.handle_key(key),
                 AppEvent::Mouse(mouse) => self.handle_mouse(mouse),
                 AppEvent::Resize => {}
                 AppEvent::Tick => {}
             }
-
-            // Clear copy feedback after 2 seconds
-            if let Some(t) = self.copy_feedback {
-                if t.elapsed() >= Duration::from_secs(2) {
-                    self.copy_feedback = None;
-                }
+            // Moving the selection while following retargets the follow
+            if self.followed_pid.is_some()
+                && selected_before.0 == Tab::Processes
+                && self.current_tab == Tab::Processes
+                && self.process_selected != selected_before.1
+            {
+                self.followed_pid = self
+                    .metrics
+                    .processes
+                    .process_at(self.process_selected)
+                    .map(|p| p.pid);
             }
 
+            self.toasts
+                .retain(|(_, created, _)| created.elapsed() < TOAST_DURATION);
+
             // Periodic refresh
-            if last_refresh.elapsed() >= self.refresh_rate {
+            if last_refresh.elapsed() >= self.effective_refresh_rate() {
                 self.metrics.refresh(self.current_tab);
-                self.log_stream.poll();
+                for err in self.metrics.ai.take_errors() {
+                    self.push_toast(err, ToastLevel::Error);
+                }
+                if self.log_stream.poll() {
+                    // Hold the view on the error that paused it
+                    if let Some(idx) = self.log_stream.last_error_index() {
+                        self.log_selected = idx;
+                        if self.current_tab == Tab::Logs {
+                            self.scroll_offset = idx.saturating_sub(self.viewport_height / 2);
+                        }
+                    }
+                }
+                self.alerts.update(&self.metrics);
+                self.alerts.acknowledge(self.current_tab);
+                if let Some(ref mut recorder) = self.recorder {
+                    if let Err(e) = recorder.record(&self.metrics) {
+                        self.stop_recording(e);
+                    }
+                }
                 last_refresh = Instant::now();
             }
         }
 
+        if let Some(ref mut recorder) = self.recorder {
+            if let Err(e) = recorder.flush() {
+                self.stop_recording(e);
+            }
+        }
+
         Ok(())
     }
 
+    /// Give up on `--record` after a failed write, keeping the session alive.
+    fn stop_recording(&mut self, err: std::io::Error) {
+        self.recorder = None;
+        self.metrics.recording = false;
+        self.push_toast(format!("Recording stopped: {err}"), ToastLevel::Error);
+    }
+
+    /// No keyboard or mouse input for [`IDLE_AFTER`].
+    pub fn is_idle(&self) -> bool {
+        self.last_input.elapsed() >= IDLE_AFTER
+    }
+
+    /// Sample interval to label the time axis with when `tab` shows a line
+    /// chart, or `None` for sparklines.
+    pub fn line_chart_interval(&self, tab: Tab) -> Option<Duration> {
+        self.line_chart_tabs
+            .contains(&tab)
+            .then_some(self.effective_refresh_rate())
+    }
+
+    /// The configured refresh rate, slowed down while idle.
+    pub fn effective_refresh_rate(&self) -> Duration {
+        if self.is_idle() {
+            self.refresh_rate.max(IDLE_REFRESH_RATE)
+        } else {
+            self.refresh_rate
+        }
+    }
+
     fn handle_key(&mut self, key: KeyEvent) {
+        self.last_input = Instant::now();
+
+        // Quit confirmation mode (Ctrl+C confirms too)
+        if self.confirm_quit {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.running = false,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.running = false;
+                }
+                _ => self.confirm_quit = false,
+            }
+            return;
+        }
+
         // Kill confirmation mode
-        if let Some((pid, _)) = &self.confirm_kill {
+        if let Some((pid, name)) = self.confirm_kill.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.terminate(pid, &name);
+            }
+            return;
+        }
+
+        // Signal menu: pick a signal, then confirm it
+        if let Some((pid, name, selected)) = self.signal_menu.take() {
             match key.code {
-                KeyCode::Char('y') | KeyCode::Char('Y') => {
-                    let pid = *pid;
-                    unsafe {
-                        libc::kill(pid as i32, libc::SIGTERM);
+                KeyCode::Char('j') | KeyCode::Down => {
+                    let next = (selected + 1) % Signal::ALL.len();
+                    self.signal_menu = Some((pid, name, next));
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    let prev = (selected + Signal::ALL.len() - 1) % Signal::ALL.len();
+                    self.signal_menu = Some((pid, name, prev));
+                }
+                KeyCode::Enter => {
+                    self.confirm_signal = Some((pid, name, Signal::ALL[selected]));
+                }
+                _ => {}
+            }
+            return;
+        }
+        if let Some((pid, name, signal)) = self.confirm_signal.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                match send_signal(pid, signal) {
+                    Ok(()) => self.push_toast(
+                        format!("Sent {} to {name} ({pid})", signal.name()),
+                        ToastLevel::Info,
+                    ),
+                    Err(e) => {
+                        self.push_toast(format!("{} {pid}: {e}", signal.name()), ToastLevel::Error)
                     }
-                    self.confirm_kill = None;
                 }
-                _ => {
-                    self.confirm_kill = None;
+            }
+            return;
+        }
+
+        // Renice confirmation mode
+        if let Some((pid, _, nice)) = self.confirm_renice.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                if let Err(e) = set_priority(pid, nice) {
+                    let reason = match e.raw_os_error() {
+                        Some(libc::EACCES) | Some(libc::EPERM) => {
+                            "permission denied (raising priority needs root)".to_string()
+                        }
+                        _ => e.to_string(),
+                    };
+                    self.push_toast(format!("renice {pid}: {reason}"), ToastLevel::Error);
                 }
             }
             return;
@@ -141,9 +440,52 @@ impl App {
             return;
         }
 
+        // Any key on the Logs tab acknowledges an error pause
+        if self.current_tab == Tab::Logs && self.log_stream.error_pending {
+            self.log_stream.acknowledge_error();
+            return;
+        }
+
         // Help overlay
         if self.show_help {
-            self.show_help = false;
+            let max = self.help_max_scroll;
+            match key.code {
+                KeyCode::Char('?') | KeyCode::Esc | KeyCode::Char('q') => self.show_help = false,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.help_scroll = (self.help_scroll + 1).min(max);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.help_scroll = self.help_scroll.saturating_sub(1);
+                }
+                KeyCode::PageDown => {
+                    self.help_scroll = (self.help_scroll + self.viewport_height).min(max);
+                }
+                KeyCode::PageUp => {
+                    self.help_scroll = self.help_scroll.saturating_sub(self.viewport_height);
+                }
+                KeyCode::Char('g') | KeyCode::Home => self.help_scroll = 0,
+                KeyCode::Char('G') | KeyCode::End => self.help_scroll = max,
+                // Jump to the next / previous section header
+                KeyCode::Char('n') => {
+                    let starts = crate::ui::help::section_starts(self.current_tab);
+                    if let Some(&next) = starts.iter().find(|&&s| s > self.help_scroll) {
+                        self.help_scroll = next.min(max);
+                    }
+                }
+                KeyCode::Char('N') => {
+                    let starts = crate::ui::help::section_starts(self.current_tab);
+                    if let Some(&prev) = starts.iter().rev().find(|&&s| s < self.help_scroll) {
+                        self.help_scroll = prev;
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Process inspector overlay
+        if self.inspect_pid.is_some() {
+            self.close_inspector();
             return;
         }
 
@@ -165,6 +507,26 @@ impl App {
                         self.metrics.ai.dismiss_search();
                     }
                 }
+                KeyCode::Char('y') => {
+                    // Copy the pull command to run elsewhere
+                    if let Some(name) = self.metrics.ai.selected_search_model() {
+                        let command = format!("ollama pull {name}");
+                        self.copy_with_message(&command, format!("Copied: {command}"));
+                    }
+                }
+                KeyCode::Char('O') => {
+                    // Full model card on ollama.com
+                    if let Some(url) = self.metrics.ai.selected_search_url() {
+                        if crate::util::open_url(&url) {
+                            self.push_toast(format!("Opened {url}"), ToastLevel::Info);
+                        } else {
+                            self.push_toast(
+                                "Open failed: could not run open".to_string(),
+                                ToastLevel::Error,
+                            );
+                        }
+                    }
+                }
                 KeyCode::Char('S') => {
                     // Start new search from within results overlay
                     self.ai_input_mode = AiInputMode::SearchInput;
@@ -197,7 +559,7 @@ impl App {
                                 content: String::new(),
                             });
                             // Start streaming chat
-                            let messages = self.metrics.ai.chat_messages.clone();
+                            let messages = self.metrics.ai.context_messages();
                             self.metrics.ai.start_chat(&model, &messages);
                             // Auto-scroll to bottom for new generation
                             self.ai_chat_scroll = 0;
@@ -243,6 +605,55 @@ impl App {
             return;
         }
 
+        // Process search input handling
+        if self.search_mode {
+            match key.code {
+                KeyCode::Esc => {
+                    self.search_mode = false;
+                    self.filter_buffer.clear();
+                }
+                KeyCode::Enter => {
+                    self.search_mode = false;
+                    self.metrics.processes.search_query = self.filter_buffer.clone();
+                    self.filter_buffer.clear();
+                    self.jump_to_match(true);
+                }
+                KeyCode::Backspace => {
+                    self.filter_buffer.pop();
+                }
+                KeyCode::Char(c) => {
+                    self.filter_buffer.push(c);
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Log line number input handling
+        if self.goto_mode {
+            match key.code {
+                KeyCode::Esc => {
+                    self.goto_mode = false;
+                    self.filter_buffer.clear();
+                }
+                KeyCode::Enter => {
+                    self.goto_mode = false;
+                    if let Ok(line) = self.filter_buffer.parse::<usize>() {
+                        self.jump_to_log_line(line);
+                    }
+                    self.filter_buffer.clear();
+                }
+                KeyCode::Backspace => {
+                    self.filter_buffer.pop();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() => {
+                    self.filter_buffer.push(c);
+                }
+                _ => {}
+            }
+            return;
+        }
+
         // Filter mode input handling
         if self.filter_mode {
             match key.code {
@@ -278,7 +689,12 @@ impl App {
         // Global keys
         match key.code {
             KeyCode::Char('q') => {
-                self.running = false;
+                if self.always_confirm_quit || self.metrics.ai.chat_status == ChatStatus::Generating
+                {
+                    self.confirm_quit = true;
+                } else {
+                    self.running = false;
+                }
                 return;
             }
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -287,6 +703,7 @@ impl App {
             }
             KeyCode::Char('?') => {
                 self.show_help = true;
+                self.help_scroll = 0;
                 return;
             }
             _ => {}
@@ -306,8 +723,8 @@ impl App {
             KeyCode::Char('0') => self.switch_tab(Tab::Temperatures),
 
             // Tab cycling
-            KeyCode::Tab => self.switch_tab(self.current_tab.next()),
-            KeyCode::BackTab => self.switch_tab(self.current_tab.prev()),
+            KeyCode::Tab => self.cycle_tab(true),
+            KeyCode::BackTab => self.cycle_tab(false),
 
             // Function keys
             KeyCode::F(n) if (1..=10).contains(&n) => {
@@ -327,10 +744,105 @@ impl App {
                 let new_ms = (ms + 250).min(10000);
                 self.refresh_rate = Duration::from_millis(new_ms);
             }
+            KeyCode::Char('*') => {
+                // A custom rate set with +/- restarts the cycle at Normal
+                let preset = RefreshPreset::from_rate(self.refresh_rate)
+                    .map_or(RefreshPreset::Normal, RefreshPreset::next);
+                self.refresh_rate = preset.rate();
+            }
+
+            // Hide the header and footer on short terminals
+            KeyCode::Char('H') => self.show_chrome = !self.show_chrome,
+
+            // Markdown system report
+            KeyCode::Char('M') => self.export_report(),
+
+            // One-line summary for chats and tickets
+            KeyCode::Char('I') => {
+                let summary = self.metrics.summary_line(&self.hostname);
+                self.copy_with_message(&summary, "Copied system summary".to_string());
+            }
+
+            // Restart every sparkline from empty
+            KeyCode::Char('R') => {
+                self.metrics.reset_histories();
+                self.push_toast("Histories reset".to_string(), ToastLevel::Info);
+            }
+
+            // Main panel fills the body
+            KeyCode::Char('z') if self.current_tab.zoomable() => self.zoomed = !self.zoomed,
+
+            // Line chart instead of sparklines on the CPU, Memory and Network tabs
+            KeyCode::Char('L')
+                if matches!(self.current_tab, Tab::Cpu | Tab::Memory | Tab::Network) =>
+            {
+                let was_chart = self.line_chart_tabs.remove(&self.current_tab);
+                if !was_chart {
+                    self.line_chart_tabs.insert(self.current_tab);
+                }
+            }
+
+            // Sparkline resolution
+            KeyCode::Char('b') => {
+                self.render_options.hi_res = !self.render_options.hi_res;
+            }
+
+            // Average vs max per column in downsampled sparklines
+            KeyCode::Char('v') => {
+                let swapped = !self.render_options.swap_downsample;
+                self.render_options.swap_downsample = swapped;
+                let text = if swapped {
+                    "Downsampled sparklines: max for CPU/memory, average for network/disk"
+                } else {
+                    "Downsampled sparklines: average for CPU/memory, max for network/disk"
+                };
+                self.push_toast(text.to_string(), ToastLevel::Info);
+            }
+
+            // Binary vs decimal byte units
+            KeyCode::Char('#') => {
+                let mode = match crate::util::unit_mode() {
+                    UnitMode::Binary => UnitMode::Decimal,
+                    UnitMode::Decimal => UnitMode::Binary,
+                };
+                crate::util::set_unit_mode(mode);
+                self.push_toast(format!("Showing {} units", mode.label()), ToastLevel::Info);
+            }
+
+            // Smoothed vs raw CPU sparklines
+            KeyCode::Char('A') => {
+                let cpu = &mut self.metrics.cpu;
+                cpu.smooth = !cpu.smooth;
+                let text = if cpu.smooth {
+                    format!("CPU sparkline smoothed (alpha {})", cpu.smoothing_alpha)
+                } else {
+                    "CPU sparkline raw".to_string()
+                };
+                self.push_toast(text, ToastLevel::Info);
+            }
+
+            // CPU usage averaged across cores vs summed like top
+            KeyCode::Char('%') => {
+                let cpu = &mut self.metrics.cpu;
+                cpu.scale = match cpu.scale {
+                    CpuScale::Normalized => CpuScale::Total,
+                    CpuScale::Total => CpuScale::Normalized,
+                };
+                let text = format!("CPU usage scale: {}", cpu.scale.label());
+                self.push_toast(text, ToastLevel::Info);
+            }
 
             // Scroll / selection
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.scroll_half_page(true);
+            }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.scroll_half_page(false);
+            }
             KeyCode::Char('j') | KeyCode::Down => match self.current_tab {
                 Tab::Temperatures => self.metrics.temperature.select_next(),
+                Tab::Network => self.metrics.network.select_next(),
+                Tab::Gpu => self.metrics.gpu.select_next(),
                 Tab::Ai => {
                     if self.metrics.ai.chat_messages.is_empty() {
                         self.metrics.ai.select_next();
@@ -340,7 +852,7 @@ impl App {
                     }
                 }
                 Tab::Processes => {
-                    let count = self.metrics.processes.filtered_count();
+                    let count = self.metrics.processes.displayed_count();
                     if count > 0 {
                         self.process_selected = (self.process_selected + 1).min(count - 1);
                         // Auto-scroll to keep selection visible
@@ -349,10 +861,13 @@ impl App {
                         }
                     }
                 }
+                Tab::Logs => self.move_log_selection(1),
                 _ => self.scroll_offset = self.scroll_offset.saturating_add(1),
             },
             KeyCode::Char('k') | KeyCode::Up => match self.current_tab {
                 Tab::Temperatures => self.metrics.temperature.select_prev(),
+                Tab::Network => self.metrics.network.select_prev(),
+                Tab::Gpu => self.metrics.gpu.select_prev(),
                 Tab::Ai => {
                     if self.metrics.ai.chat_messages.is_empty() {
                         self.metrics.ai.select_prev();
@@ -367,6 +882,7 @@ impl App {
                         self.scroll_offset = self.process_selected;
                     }
                 }
+                Tab::Logs => self.move_log_selection(-1),
                 _ => self.scroll_offset = self.scroll_offset.saturating_sub(1),
             },
             KeyCode::Char('g') => {
@@ -374,6 +890,9 @@ impl App {
                 if self.current_tab == Tab::Processes {
                     self.process_selected = 0;
                 }
+                if self.current_tab == Tab::Logs {
+                    self.log_selected = 0;
+                }
                 if self.current_tab == Tab::Ai {
                     self.ai_chat_scroll = usize::MAX; // jump to top (max offset from bottom)
                 }
@@ -381,16 +900,19 @@ impl App {
             KeyCode::Char('G') => {
                 self.scroll_offset = usize::MAX;
                 if self.current_tab == Tab::Processes {
-                    let count = self.metrics.processes.filtered_count();
+                    let count = self.metrics.processes.displayed_count();
                     self.process_selected = count.saturating_sub(1);
                 }
+                if self.current_tab == Tab::Logs {
+                    self.log_selected = self.log_stream.filtered_entries().len().saturating_sub(1);
+                }
                 if self.current_tab == Tab::Ai {
                     self.ai_chat_scroll = 0; // jump to bottom (follow)
                 }
             }
             KeyCode::PageDown => {
                 if self.current_tab == Tab::Processes {
-                    let count = self.metrics.processes.filtered_count();
+                    let count = self.metrics.processes.displayed_count();
                     self.process_selected =
                         (self.process_selected + self.viewport_height).min(count.saturating_sub(1));
                     self.scroll_offset = self.scroll_offset.saturating_add(self.viewport_height);
@@ -414,22 +936,119 @@ impl App {
                 }
             }
 
+            // Network: include interfaces without traffic
+            KeyCode::Char('a') if self.current_tab == Tab::Network => {
+                self.metrics.network.toggle_show_all();
+            }
+
+            // Network: capture peak throughput during a speed test
+            KeyCode::Char('T') if self.current_tab == Tab::Network => {
+                let text = if self.metrics.network.toggle_bandwidth_test() {
+                    "Bandwidth test started"
+                } else {
+                    "Bandwidth test stopped"
+                };
+                self.push_toast(text.to_string(), ToastLevel::Info);
+            }
+
+            // Dashboard layout density
+            KeyCode::Char('d') if self.current_tab == Tab::Dashboard => {
+                self.dashboard_density = self.dashboard_density.toggle();
+            }
+
+            // Follow mode: pin the selection to a PID instead of a row
+            KeyCode::Char('F') if self.current_tab == Tab::Processes => {
+                self.followed_pid = match self.followed_pid {
+                    Some(_) => None,
+                    None => self
+                        .metrics
+                        .processes
+                        .process_at(self.process_selected)
+                        .map(|p| p.pid),
+                };
+            }
+
+            // Jump to a log line by number
+            KeyCode::Char(':') if self.current_tab == Tab::Logs => {
+                self.goto_mode = true;
+                self.filter_buffer.clear();
+            }
+
+            // Process search (jump to matches without hiding the rest)
+            KeyCode::Char('f') if self.current_tab == Tab::Processes => {
+                self.search_mode = true;
+                self.filter_buffer.clear();
+            }
+            KeyCode::Char('n')
+                if self.current_tab == Tab::Processes
+                    && !self.metrics.processes.search_query.is_empty() =>
+            {
+                self.jump_to_match(true);
+            }
+            KeyCode::Char('N')
+                if self.current_tab == Tab::Processes
+                    && !self.metrics.processes.search_query.is_empty() =>
+            {
+                self.jump_to_match(false);
+            }
+            KeyCode::Esc
+                if self.current_tab == Tab::Processes
+                    && !self.metrics.processes.search_query.is_empty() =>
+            {
+                self.metrics.processes.search_query.clear();
+            }
+
             // Process sort keys
             KeyCode::Char('c') if self.current_tab == Tab::Processes => {
-                self.metrics.processes.set_sort_field(ProcessSortField::Cpu);
+                self.change_process_sort(ProcessSortField::Cpu);
             }
             KeyCode::Char('m') if self.current_tab == Tab::Processes => {
-                self.metrics
-                    .processes
-                    .set_sort_field(ProcessSortField::Memory);
+                self.change_process_sort(ProcessSortField::Memory);
             }
             KeyCode::Char('p') if self.current_tab == Tab::Processes => {
-                self.metrics.processes.set_sort_field(ProcessSortField::Pid);
+                self.change_process_sort(ProcessSortField::Pid);
             }
             KeyCode::Char('n') if self.current_tab == Tab::Processes => {
-                self.metrics
-                    .processes
-                    .set_sort_field(ProcessSortField::Name);
+                self.change_process_sort(ProcessSortField::Name);
+            }
+            KeyCode::Char('e') if self.current_tab == Tab::Processes => {
+                self.change_process_sort(ProcessSortField::Energy);
+            }
+            KeyCode::Char('S') if self.current_tab == Tab::Processes => {
+                self.change_process_sort(ProcessSortField::State);
+            }
+
+            // Disk sort keys
+            KeyCode::Char('u') if self.current_tab == Tab::Disk => {
+                self.metrics.disk.set_sort_field(DiskSortField::Used);
+            }
+            KeyCode::Char('s') if self.current_tab == Tab::Disk => {
+                self.metrics.disk.set_sort_field(DiskSortField::Size);
+            }
+            KeyCode::Char('i') if self.current_tab == Tab::Disk => {
+                self.metrics.disk.set_sort_field(DiskSortField::Io);
+            }
+
+            // Horizontal column scroll (PID and Name stay pinned)
+            KeyCode::Left if self.current_tab == Tab::Processes => {
+                self.col_offset = self.col_offset.saturating_sub(1);
+            }
+            KeyCode::Right if self.current_tab == Tab::Processes => {
+                let max = max_col_offset(self.metrics.processes.table_columns().len());
+                self.col_offset = (self.col_offset + 1).min(max);
+            }
+
+            // Fuzzy vs substring filter matching
+            KeyCode::Char('~') if self.current_tab == Tab::Processes => {
+                self.metrics.processes.toggle_fuzzy();
+                self.process_selected = 0;
+                self.scroll_offset = 0;
+                let state = if self.metrics.processes.fuzzy {
+                    "on"
+                } else {
+                    "off"
+                };
+                self.push_toast(format!("Fuzzy filter {state}"), ToastLevel::Info);
             }
 
             // Tree view toggle
@@ -439,11 +1058,76 @@ impl App {
                 self.scroll_offset = 0;
             }
 
+            // Hide root/system-account processes
+            KeyCode::Char('s') if self.current_tab == Tab::Processes => {
+                self.metrics.processes.toggle_hide_system();
+                self.process_selected = 0;
+                self.scroll_offset = 0;
+            }
+
+            // Only the current user's processes
+            KeyCode::Char('u') if self.current_tab == Tab::Processes => {
+                self.metrics.processes.toggle_only_mine();
+                self.process_selected = 0;
+                self.scroll_offset = 0;
+            }
+
+            // Inspect process
+            KeyCode::Enter if self.current_tab == Tab::Processes => {
+                if let Some(proc) = self.metrics.processes.process_at(self.process_selected) {
+                    self.inspect_pid = Some(proc.pid);
+                    self.metrics.processes.inspect(proc.pid);
+                }
+            }
+
+            // Renice: '>' lowers priority (higher nice), '<' raises it
+            KeyCode::Char('>') | KeyCode::Char('<') if self.current_tab == Tab::Processes => {
+                if let Some(proc) = self.metrics.processes.process_at(self.process_selected) {
+                    let step = if key.code == KeyCode::Char('>') {
+                        1
+                    } else {
+                        -1
+                    };
+                    let nice = (proc.priority + step).clamp(-20, 19);
+                    if nice != proc.priority {
+                        self.confirm_renice = Some((proc.pid, proc.name, nice));
+                    }
+                }
+            }
+
+            // Copy the selected PID / command line
+            KeyCode::Char('y') if self.current_tab == Tab::Processes => {
+                if let Some(proc) = self.metrics.processes.process_at(self.process_selected) {
+                    self.copy_with_toast(&proc.pid.to_string());
+                }
+            }
+            KeyCode::Char('Y') if self.current_tab == Tab::Processes => {
+                if let Some(proc) = self.metrics.processes.process_at(self.process_selected) {
+                    match self.metrics.command_line(proc.pid) {
+                        Some(cmd) => self.copy_with_toast(&cmd),
+                        None => self.push_toast(
+                            format!("No command line readable for PID {}", proc.pid),
+                            ToastLevel::Error,
+                        ),
+                    }
+                }
+            }
+
             // Kill process
             KeyCode::Char('K') if self.current_tab == Tab::Processes => {
-                let filtered = self.metrics.processes.filtered_processes();
-                if let Some(proc) = filtered.get(self.process_selected) {
-                    self.confirm_kill = Some((proc.pid, proc.name.clone()));
+                if let Some(proc) = self.metrics.processes.process_at(self.process_selected) {
+                    if self.quick_kill.contains(&proc.name) {
+                        self.terminate(proc.pid, &proc.name);
+                    } else {
+                        self.confirm_kill = Some((proc.pid, proc.name));
+                    }
+                }
+            }
+
+            // Signal menu for the selected process
+            KeyCode::Char('x') if self.current_tab == Tab::Processes => {
+                if let Some(proc) = self.metrics.processes.process_at(self.process_selected) {
+                    self.signal_menu = Some((proc.pid, proc.name, 0));
                 }
             }
 
@@ -458,11 +1142,26 @@ impl App {
                     self.metrics.ai.load_model(&name);
                 }
             }
+            KeyCode::Char('P') if self.current_tab == Tab::Ai => {
+                if let Some(name) = self.metrics.ai.selected_model_name() {
+                    let msg = if self.metrics.ai.toggle_pin(&name) {
+                        format!("Keeping {name} loaded")
+                    } else {
+                        format!("{name} will unload when idle")
+                    };
+                    self.push_toast(msg, ToastLevel::Info);
+                }
+            }
             KeyCode::Char('U') if self.current_tab == Tab::Ai => {
                 if let Some(name) = self.metrics.ai.selected_model_name() {
                     self.metrics.ai.unload_model(&name);
                 }
             }
+            KeyCode::Char('B') if self.current_tab == Tab::Ai => {
+                if let Some(name) = self.metrics.ai.selected_model_name() {
+                    self.metrics.ai.start_benchmark(&name);
+                }
+            }
             KeyCode::Char('i') if self.current_tab == Tab::Ai => {
                 if self.metrics.ai.has_loaded_model() {
                     self.ai_input_mode = AiInputMode::ChatInput;
@@ -480,20 +1179,26 @@ impl App {
                 self.ai_chat_scroll = 0;
             }
             KeyCode::Esc if self.current_tab == Tab::Ai => {
-                self.metrics.ai.cancel_chat();
+                let ai = &mut self.metrics.ai;
+                if ai.chat_status != ChatStatus::Generating && ai.is_pulling() {
+                    let name = ai.pull_model_name.clone().unwrap_or_default();
+                    ai.cancel_pull();
+                    self.push_toast(format!("Stopped pulling {name}"), ToastLevel::Info);
+                } else {
+                    ai.cancel_chat();
+                }
             }
             KeyCode::Char('y') if self.current_tab == Tab::Ai => {
-                if let Some(msg) = self
+                if let Some(content) = self
                     .metrics
                     .ai
                     .chat_messages
                     .iter()
                     .rev()
                     .find(|m| m.role == "assistant" && !m.content.is_empty())
+                    .map(|m| m.content.clone())
                 {
-                    if crate::util::copy_to_clipboard(&msg.content) {
-                        self.copy_feedback = Some(Instant::now());
-                    }
+                    self.copy_with_toast(&content);
                 }
             }
             KeyCode::Char('Y') if self.current_tab == Tab::Ai => {
@@ -510,9 +1215,7 @@ impl App {
                         })
                         .collect::<Vec<_>>()
                         .join("\n\n");
-                    if crate::util::copy_to_clipboard(&text) {
-                        self.copy_feedback = Some(Instant::now());
-                    }
+                    self.copy_with_toast(&text);
                 }
             }
 
@@ -523,29 +1226,293 @@ impl App {
             KeyCode::Char('a') if self.current_tab == Tab::Logs => {
                 self.log_stream.toggle_auto_scroll();
             }
+            KeyCode::Char('t') if self.current_tab == Tab::Logs => {
+                self.log_stream.toggle_relative_time();
+            }
+            KeyCode::Char('r') if self.current_tab == Tab::Logs => {
+                self.log_stream.restart();
+                match &self.log_stream.error {
+                    Some(err) => {
+                        let msg = format!("log stream unavailable: {err}");
+                        self.push_toast(msg, ToastLevel::Error);
+                    }
+                    None => self.push_toast("Log stream restarted".to_string(), ToastLevel::Info),
+                }
+            }
+            KeyCode::Char('w') if self.current_tab == Tab::Logs => {
+                self.log_wrap = !self.log_wrap;
+                self.log_hscroll = 0;
+            }
+            KeyCode::Left if self.current_tab == Tab::Logs && !self.log_wrap => {
+                self.log_hscroll = self.log_hscroll.saturating_sub(LOG_HSCROLL_STEP);
+            }
+            KeyCode::Right if self.current_tab == Tab::Logs && !self.log_wrap => {
+                self.log_hscroll = self.log_hscroll.saturating_add(LOG_HSCROLL_STEP);
+            }
+            KeyCode::Char('C') if self.current_tab == Tab::Logs => {
+                self.log_stream.clear();
+                self.log_selected = 0;
+                self.scroll_offset = 0;
+            }
+            KeyCode::Char('p') if self.current_tab == Tab::Logs => {
+                self.log_stream.toggle_pause_on_error();
+            }
+            KeyCode::Char('y') if self.current_tab == Tab::Logs => {
+                let entries = self.log_stream.filtered_entries();
+                // While auto-scrolling the newest line is the one on screen
+                let idx = if self.log_stream.auto_scroll {
+                    entries.len().checked_sub(1)
+                } else {
+                    Some(self.log_selected)
+                };
+                if let Some(line) = idx.and_then(|i| entries.get(i)).map(|e| e.to_line()) {
+                    self.copy_with_toast(&line);
+                }
+            }
+            KeyCode::Char('E') if self.current_tab == Tab::Logs => match self.log_stream.export() {
+                Ok(path) => {
+                    self.push_toast(format!("Exported to {}", path.display()), ToastLevel::Info)
+                }
+                Err(e) => self.push_toast(format!("Export failed: {e}"), ToastLevel::Error),
+            },
 
             _ => {}
         }
     }
 
+    /// Ctrl-D / Ctrl-U: move half a viewport, carrying the selection along
+    /// on tabs that have one.
+    fn scroll_half_page(&mut self, down: bool) {
+        let half = (self.viewport_height / 2).max(1);
+        match self.current_tab {
+            Tab::Processes => {
+                let count = self.metrics.processes.displayed_count();
+                if count > 0 {
+                    let idx = if down {
+                        (self.process_selected + half).min(count - 1)
+                    } else {
+                        self.process_selected.saturating_sub(half)
+                    };
+                    self.select_process(idx);
+                }
+            }
+            Tab::Logs => self.move_log_selection(if down {
+                half as isize
+            } else {
+                -(half as isize)
+            }),
+            Tab::Ai => {
+                // Chat scroll counts up from the bottom
+                self.ai_chat_scroll = if down {
+                    self.ai_chat_scroll.saturating_sub(half)
+                } else {
+                    self.ai_chat_scroll.saturating_add(half)
+                };
+            }
+            _ => {
+                self.scroll_offset = if down {
+                    self.scroll_offset.saturating_add(half)
+                } else {
+                    self.scroll_offset.saturating_sub(half)
+                };
+            }
+        }
+    }
+
+    /// Move the log selection, leaving auto-scroll so the view holds still.
+    fn move_log_selection(&mut self, delta: isize) {
+        let count = self.log_stream.filtered_entries().len();
+        if count == 0 {
+            return;
+        }
+        if self.log_stream.auto_scroll {
+            self.log_stream.auto_scroll = false;
+            self.log_selected = count - 1;
+            self.scroll_offset = count.saturating_sub(self.viewport_height);
+        }
+        self.log_selected = self
+            .log_selected
+            .saturating_add_signed(delta)
+            .min(count - 1);
+        if self.log_selected < self.scroll_offset {
+            self.scroll_offset = self.log_selected;
+        } else if self.log_selected >= self.scroll_offset + self.viewport_height {
+            self.scroll_offset = self.log_selected + 1 - self.viewport_height;
+        }
+    }
+
+    /// Select 1-based `line` of the filtered log view, clamped to what's
+    /// there, and center it.
+    fn jump_to_log_line(&mut self, line: usize) {
+        let count = self.log_stream.filtered_entries().len();
+        if count == 0 {
+            return;
+        }
+        let idx = line.clamp(1, count) - 1;
+        self.log_stream.auto_scroll = false;
+        self.log_selected = idx;
+        self.scroll_offset = idx.saturating_sub(self.viewport_height / 2);
+        self.push_toast(format!("Line {} of {count}", idx + 1), ToastLevel::Info);
+    }
+
+    fn close_inspector(&mut self) {
+        self.inspect_pid = None;
+        self.metrics.processes.stop_inspect();
+    }
+
+    fn jump_to_match(&mut self, forward: bool) {
+        if let Some(idx) = self
+            .metrics
+            .processes
+            .find_match(self.process_selected, forward)
+        {
+            self.select_process(idx);
+        }
+    }
+
+    /// Queue a message in the bottom-right corner until `TOAST_DURATION` passes.
+    pub fn push_toast(&mut self, text: String, level: ToastLevel) {
+        self.toasts.push((text, Instant::now(), level));
+    }
+
+    fn copy_with_toast(&mut self, text: &str) {
+        self.copy_with_message(text, "Copied to clipboard".to_string());
+    }
+
+    /// Copy `text`, confirming with `message` or reporting the failure.
+    fn copy_with_message(&mut self, text: &str, message: String) {
+        if crate::util::copy_to_clipboard(text) {
+            self.push_toast(message, ToastLevel::Info);
+        } else {
+            self.push_toast(
+                "Copy failed: could not run pbcopy".to_string(),
+                ToastLevel::Error,
+            );
+        }
+    }
+
+    /// Write a Markdown snapshot of the current sample to the working
+    /// directory. Subsystems hidden since their tab was last shown report
+    /// their last values.
+    fn export_report(&mut self) {
+        let path = format!("peakmon-report-{}.md", local_stamp(now_secs()));
+        match std::fs::write(
+            &path,
+            self.metrics.to_markdown(&self.hostname, self.temp_unit),
+        ) {
+            Ok(()) => self.push_toast(format!("Report written to {path}"), ToastLevel::Info),
+            Err(e) => self.push_toast(format!("Report failed: {e}"), ToastLevel::Error),
+        }
+    }
+
+    /// Re-sort the process list, keeping the selected process selected.
+    fn change_process_sort(&mut self, field: ProcessSortField) {
+        let selected_pid = self
+            .metrics
+            .processes
+            .process_at(self.process_selected)
+            .map(|p| p.pid);
+        self.metrics.processes.set_sort_field(field);
+        let idx = selected_pid
+            .and_then(|pid| self.metrics.processes.position_of(pid))
+            .unwrap_or(0);
+        self.select_process(idx);
+    }
+
+    /// Ask `pid` to exit, reporting failures (already gone, not ours).
+    fn terminate(&mut self, pid: u32, name: &str) {
+        match send_signal(pid, Signal::Term) {
+            Ok(()) => self.push_toast(format!("Sent SIGTERM to {name} ({pid})"), ToastLevel::Info),
+            Err(e) => self.push_toast(format!("SIGTERM {pid}: {e}"), ToastLevel::Error),
+        }
+    }
+
+    /// Select a process row and scroll just enough to keep it visible.
+    fn select_process(&mut self, idx: usize) {
+        self.process_selected = idx;
+        if idx < self.scroll_offset {
+            self.scroll_offset = idx;
+        } else if idx >= self.scroll_offset + self.viewport_height {
+            self.scroll_offset = idx + 1 - self.viewport_height;
+        }
+    }
+
+    /// Move the selection to wherever the followed PID now sorts, dropping
+    /// the follow once the process is gone.
+    fn sync_followed_process(&mut self) {
+        let Some(pid) = self.followed_pid else {
+            return;
+        };
+        match self.metrics.processes.position_of(pid) {
+            Some(idx) => self.select_process(idx),
+            None => self.followed_pid = None,
+        }
+    }
+
+    /// The footer stays visible with the chrome hidden while it's taking input.
+    pub fn footer_visible(&self) -> bool {
+        self.show_chrome || self.filter_mode || self.search_mode || self.goto_mode
+    }
+
+    /// Move to the next or previous tab, stopping at the ends unless
+    /// `wrap_tabs` is set.
+    fn cycle_tab(&mut self, forward: bool) {
+        let current = self.current_tab;
+        let tab = if forward {
+            current.next()
+        } else {
+            current.prev()
+        };
+        let wrapped = if forward {
+            tab.index() < current.index()
+        } else {
+            tab.index() > current.index()
+        };
+        if wrapped && !self.wrap_tabs {
+            return;
+        }
+        self.switch_tab(tab);
+    }
+
     fn switch_tab(&mut self, tab: Tab) {
         self.current_tab = tab;
+        // Subsystems skipped while their tab was hidden catch up right away
+        // instead of on the next tick
+        if self.metrics.is_stale(tab) {
+            self.metrics.refresh(tab);
+        }
+        self.alerts.acknowledge(tab);
         self.scroll_offset = 0;
         if tab == Tab::Processes {
             self.process_selected = 0;
         }
+        if tab == Tab::Logs && self.log_stream.error_pending {
+            self.scroll_offset = self.log_selected.saturating_sub(self.viewport_height / 2);
+        }
     }
 
     fn handle_mouse(&mut self, mouse: MouseEvent) {
+        self.last_input = Instant::now();
+
         // Dismiss overlays on any click
         if self.show_help
+            || self.inspect_pid.is_some()
             || self.confirm_kill.is_some()
+            || self.confirm_quit
+            || self.confirm_renice.is_some()
+            || self.signal_menu.is_some()
+            || self.confirm_signal.is_some()
             || self.ai_confirm_delete.is_some()
             || self.metrics.ai.show_search
         {
             if matches!(mouse.kind, MouseEventKind::Down(_)) {
                 self.show_help = false;
+                self.close_inspector();
                 self.confirm_kill = None;
+                self.confirm_quit = false;
+                self.confirm_renice = None;
+                self.signal_menu = None;
+                self.confirm_signal = None;
                 self.ai_confirm_delete = None;
                 self.metrics.ai.dismiss_search();
             }
@@ -573,7 +1540,7 @@ impl App {
             }
             MouseEventKind::ScrollDown => {
                 if self.current_tab == Tab::Processes {
-                    let count = self.metrics.processes.filtered_count();
+                    let count = self.metrics.processes.displayed_count();
                     if count > 0 {
                         self.process_selected = (self.process_selected + 3).min(count - 1);
                         if self.process_selected >= self.scroll_offset + self.viewport_height {
@@ -597,14 +1564,28 @@ impl App {
                 let col = mouse.column;
 
                 // Tab bar is on row 1 (second row of header)
-                if row == 1 {
+                if row == 1 && self.show_chrome {
                     self.handle_tab_click(col);
+                } else if self.current_tab == Tab::Processes {
+                    self.handle_header_click(col, row);
                 }
             }
             _ => {}
         }
     }
 
+    /// Sort by the clicked process column; clicking it again flips the order.
+    fn handle_header_click(&mut self, col: u16, row: u16) {
+        let clicked = self
+            .process_header
+            .iter()
+            .find(|(cell, _)| cell.contains(Position::new(col, row)))
+            .and_then(|(_, column)| column.sort_field());
+        if let Some(field) = clicked {
+            self.change_process_sort(field);
+        }
+    }
+
     fn handle_tab_click(&mut self, col: u16) {
         // Tab bar format: " N:Label  N:Label  ..."
         // Each tab is roughly: 1 space + "N:Label" + 1 space
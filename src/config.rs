@@ -1,4 +1,24 @@
-use clap::Parser;
+use clap::parser::ValueSource;
+use clap::{ArgAction, ArgMatches, CommandFactory, FromArgMatches, Parser};
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+use serde::Deserialize;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use crate::metrics::ai::DEFAULT_OLLAMA_HOST;
+use crate::metrics::cpu::CpuScale;
+use crate::metrics::process::ProcessColumn;
+use crate::metrics::temperature::TempUnit;
+use crate::ui::tabs::Tab;
+use crate::ui::theme::PaletteKind;
+use crate::util::UnitMode;
+
+const REFRESH_RATE_RANGE: RangeInclusive<u64> = 250..=10000;
+const PROCESS_INTERVAL_RANGE: RangeInclusive<u64> = 250..=60000;
+const LOG_BUFFER_RANGE: RangeInclusive<u64> = 100..=1_000_000;
+const CHAT_CONTEXT_RANGE: RangeInclusive<u64> = 2..=1000;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -8,6 +28,414 @@ use clap::Parser;
 )]
 pub struct Config {
     /// Metrics refresh interval in milliseconds
-    #[arg(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u64).range(250..=10000))]
+    #[arg(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u64).range(REFRESH_RATE_RANGE))]
     pub refresh_rate: u64,
+
+    /// Minimum milliseconds between process list refreshes. Listing
+    /// processes is peakmon's most expensive poll, so it can run slower than
+    /// the CPU and memory refresh rate
+    #[arg(long, value_name = "MS", default_value_t = 2000, value_parser = clap::value_parser!(u64).range(PROCESS_INTERVAL_RANGE))]
+    pub process_interval: u64,
+
+    /// Tab to open on launch [default: the tab open at the last quit]
+    #[arg(long, value_name = "NAME", value_parser = parse_tab)]
+    pub tab: Option<Tab>,
+
+    /// Show sizes in binary (GiB) or decimal (GB) units
+    #[arg(long, value_name = "MODE", default_value = "binary", value_parser = parse_units)]
+    pub units: UnitMode,
+
+    /// Show temperatures in celsius or fahrenheit. --alert-temp stays in
+    /// degrees C
+    #[arg(long, value_name = "UNIT", default_value = "celsius", value_parser = parse_temp_unit)]
+    pub temp_unit: TempUnit,
+
+    /// Show overall CPU usage averaged across cores (normalized, 0-100%) or
+    /// summed like top (total, e.g. 350% for three and a half busy cores)
+    #[arg(long, value_name = "SCALE", default_value = "normalized", value_parser = parse_cpu_scale)]
+    pub cpu_scale: CpuScale,
+
+    /// Smooth the CPU sparklines with an exponential moving average, giving
+    /// the newest sample this weight (0-1, lower is smoother). The numeric
+    /// readout stays instantaneous
+    #[arg(long, value_name = "ALPHA", value_parser = parse_alpha)]
+    pub smooth_cpu: Option<f64>,
+
+    /// Colors for gauges and process states: default, or colorblind for a
+    /// blue/orange/purple set that doesn't rely on red vs green
+    #[arg(long, value_name = "NAME", default_value = "default", value_parser = parse_palette)]
+    pub palette: PaletteKind,
+
+    /// Start with the compact two-column dashboard layout
+    #[arg(long)]
+    pub compact: bool,
+
+    /// Draw sparklines with braille dots for finer vertical resolution
+    #[arg(long)]
+    pub hi_res: bool,
+
+    /// Ask before quitting with q (always asked while a chat is generating)
+    #[arg(long)]
+    pub confirm_quit: bool,
+
+    /// Tab/Shift+Tab wrap from the last tab to the first and back; false
+    /// stops at the ends
+    #[arg(long, value_name = "BOOL", default_value_t = true, action = ArgAction::Set)]
+    pub wrap_tabs: bool,
+
+    /// Start with every network interface listed, including ones without traffic
+    #[arg(long)]
+    pub all_interfaces: bool,
+
+    /// Process table columns, comma-separated, from: pid, name, user, state,
+    /// cpu, energy, mem, threads, nice, cmd
+    #[arg(long, value_name = "LIST", value_delimiter = ',', default_value = "pid,name,state,cpu,energy,mem,threads,nice", value_parser = parse_column)]
+    pub columns: Vec<ProcessColumn>,
+
+    /// Number of log lines to keep. Each line costs a few hundred bytes, so
+    /// 100000 lines is roughly tens of MB
+    #[arg(long, value_name = "LINES", default_value_t = 5000, value_parser = clap::value_parser!(u64).range(LOG_BUFFER_RANGE))]
+    pub log_buffer: u64,
+
+    /// Kill processes with this exact name without asking (repeatable)
+    #[arg(long, value_name = "NAME")]
+    pub quick_kill: Vec<String>,
+
+    /// Most recent chat messages sent to the model each turn; older ones are
+    /// dropped so long chats fit its context window
+    #[arg(long, value_name = "MESSAGES", default_value_t = 20, value_parser = clap::value_parser!(u64).range(CHAT_CONTEXT_RANGE))]
+    pub chat_context: u64,
+
+    /// Base URL of the Ollama server
+    #[arg(long, value_name = "URL", default_value = DEFAULT_OLLAMA_HOST, value_parser = parse_ollama_host)]
+    pub ollama_host: String,
+
+    /// How long a model pinned with P stays loaded: seconds, a duration
+    /// like "30m" or "24h", or -1 for indefinitely
+    #[arg(long, value_name = "DURATION", default_value = "-1", value_parser = parse_keep_alive, allow_hyphen_values = true)]
+    pub keep_alive: String,
+
+    /// Print a Markdown system report (to PATH, or stdout) and exit
+    #[arg(long, value_name = "PATH", num_args = 0..=1)]
+    pub report: Option<Option<PathBuf>>,
+
+    /// Save a JSON snapshot of the current metrics to PATH and exit
+    #[arg(long, value_name = "PATH")]
+    pub snapshot: Option<PathBuf>,
+
+    /// Print the differences between two snapshots saved with --snapshot and exit
+    #[arg(long, num_args = 2, value_names = ["BEFORE", "AFTER"])]
+    pub diff: Option<Vec<PathBuf>>,
+
+    /// Append a CSV row of metrics to this file on every refresh
+    #[arg(long, value_name = "PATH")]
+    pub record: Option<PathBuf>,
+
+    /// Alert when aggregate CPU usage stays above this percentage
+    #[arg(long, value_name = "PERCENT")]
+    pub alert_cpu: Option<f64>,
+
+    /// Alert when memory usage stays above this percentage
+    #[arg(long, value_name = "PERCENT")]
+    pub alert_mem: Option<f64>,
+
+    /// Alert when any temperature sensor stays above this many degrees C
+    #[arg(long, value_name = "CELSIUS")]
+    pub alert_temp: Option<f64>,
+
+    /// Flag volumes whose used space is above this percentage
+    #[arg(long, value_name = "PERCENT", default_value_t = 90.0, value_parser = parse_disk_full)]
+    pub disk_full: f64,
+
+    /// Ring the terminal bell when an alert fires
+    #[arg(long)]
+    pub alert_bell: bool,
+
+    /// Settings file to read instead of ~/.config/peakmon/config.toml
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+}
+
+/// Settings read from the config file. Keys mirror the long flag names;
+/// anything given on the command line wins.
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct FileConfig {
+    refresh_rate: Option<u64>,
+    process_interval: Option<u64>,
+    tab: Option<String>,
+    units: Option<String>,
+    temp_unit: Option<String>,
+    cpu_scale: Option<String>,
+    palette: Option<String>,
+    smooth_cpu: Option<f64>,
+    compact: Option<bool>,
+    hi_res: Option<bool>,
+    confirm_quit: Option<bool>,
+    wrap_tabs: Option<bool>,
+    all_interfaces: Option<bool>,
+    log_buffer: Option<u64>,
+    columns: Option<String>,
+    chat_context: Option<u64>,
+    keep_alive: Option<String>,
+    ollama_host: Option<String>,
+    quick_kill: Option<Vec<String>>,
+    alert_cpu: Option<f64>,
+    alert_mem: Option<f64>,
+    alert_temp: Option<f64>,
+    disk_full: Option<f64>,
+    alert_bell: Option<bool>,
+}
+
+impl Config {
+    /// Parse the command line, then fill in anything it left at the default
+    /// from the config file. A missing default config file is not an error.
+    pub fn load() -> Result<Self> {
+        let matches = Self::command().get_matches();
+        let mut config = Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+        let path = match &config.config {
+            Some(path) => path.clone(),
+            None => match default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(config),
+            },
+        };
+        let text = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("reading config file {}", path.display()))?;
+        let file: FileConfig = toml::from_str(&text)
+            .wrap_err_with(|| format!("parsing config file {}", path.display()))?;
+        config
+            .merge(file, &matches)
+            .wrap_err_with(|| format!("in config file {}", path.display()))?;
+        Ok(config)
+    }
+
+    fn merge(&mut self, file: FileConfig, matches: &ArgMatches) -> Result<()> {
+        let from_cli = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        if let Some(rate) = file.refresh_rate.filter(|_| !from_cli("refresh_rate")) {
+            if !REFRESH_RATE_RANGE.contains(&rate) {
+                return Err(eyre!(
+                    "refresh-rate {rate} is outside {}..={}",
+                    REFRESH_RATE_RANGE.start(),
+                    REFRESH_RATE_RANGE.end()
+                ));
+            }
+            self.refresh_rate = rate;
+        }
+        if let Some(ms) = file
+            .process_interval
+            .filter(|_| !from_cli("process_interval"))
+        {
+            if !PROCESS_INTERVAL_RANGE.contains(&ms) {
+                return Err(eyre!(
+                    "process-interval {ms} is outside {}..={}",
+                    PROCESS_INTERVAL_RANGE.start(),
+                    PROCESS_INTERVAL_RANGE.end()
+                ));
+            }
+            self.process_interval = ms;
+        }
+        if let Some(name) = file.tab.filter(|_| !from_cli("tab")) {
+            self.tab = Some(parse_tab(&name).map_err(|e| eyre!(e))?);
+        }
+        if let Some(name) = file.units.filter(|_| !from_cli("units")) {
+            self.units = parse_units(&name).map_err(|e| eyre!(e))?;
+        }
+        if let Some(name) = file.temp_unit.filter(|_| !from_cli("temp_unit")) {
+            self.temp_unit = parse_temp_unit(&name).map_err(|e| eyre!(e))?;
+        }
+        if let Some(name) = file.cpu_scale.filter(|_| !from_cli("cpu_scale")) {
+            self.cpu_scale = parse_cpu_scale(&name).map_err(|e| eyre!(e))?;
+        }
+        if let Some(alpha) = file.smooth_cpu.filter(|_| !from_cli("smooth_cpu")) {
+            self.smooth_cpu = Some(check_alpha(alpha).map_err(|e| eyre!(e))?);
+        }
+        if let Some(name) = file.palette.filter(|_| !from_cli("palette")) {
+            self.palette = parse_palette(&name).map_err(|e| eyre!(e))?;
+        }
+        if let Some(lines) = file.log_buffer.filter(|_| !from_cli("log_buffer")) {
+            if !LOG_BUFFER_RANGE.contains(&lines) {
+                return Err(eyre!(
+                    "log-buffer {lines} is outside {}..={}",
+                    LOG_BUFFER_RANGE.start(),
+                    LOG_BUFFER_RANGE.end()
+                ));
+            }
+            self.log_buffer = lines;
+        }
+
+        if let Some(messages) = file.chat_context.filter(|_| !from_cli("chat_context")) {
+            if !CHAT_CONTEXT_RANGE.contains(&messages) {
+                return Err(eyre!(
+                    "chat-context {messages} is outside {}..={}",
+                    CHAT_CONTEXT_RANGE.start(),
+                    CHAT_CONTEXT_RANGE.end()
+                ));
+            }
+            self.chat_context = messages;
+        }
+        if let Some(value) = file.keep_alive.filter(|_| !from_cli("keep_alive")) {
+            self.keep_alive = parse_keep_alive(&value).map_err(|e| eyre!(e))?;
+        }
+        if let Some(url) = file.ollama_host.filter(|_| !from_cli("ollama_host")) {
+            self.ollama_host = parse_ollama_host(&url).map_err(|e| eyre!(e))?;
+        }
+        if let Some(list) = file.columns.filter(|_| !from_cli("columns")) {
+            self.columns = list
+                .split(',')
+                .map(parse_column)
+                .collect::<Result<_, _>>()
+                .map_err(|e| eyre!(e))?;
+        }
+
+        if let Some(names) = file.quick_kill.filter(|_| !from_cli("quick_kill")) {
+            self.quick_kill = names;
+        }
+
+        let flags = [
+            (file.compact, "compact", &mut self.compact),
+            (file.hi_res, "hi_res", &mut self.hi_res),
+            (file.confirm_quit, "confirm_quit", &mut self.confirm_quit),
+            (file.wrap_tabs, "wrap_tabs", &mut self.wrap_tabs),
+            (
+                file.all_interfaces,
+                "all_interfaces",
+                &mut self.all_interfaces,
+            ),
+            (file.alert_bell, "alert_bell", &mut self.alert_bell),
+        ];
+        for (value, id, field) in flags {
+            if let Some(value) = value.filter(|_| !from_cli(id)) {
+                *field = value;
+            }
+        }
+
+        if let Some(percent) = file.disk_full.filter(|_| !from_cli("disk_full")) {
+            self.disk_full = check_disk_full(percent).map_err(|e| eyre!(e))?;
+        }
+
+        let thresholds = [
+            (file.alert_cpu, "alert_cpu", &mut self.alert_cpu),
+            (file.alert_mem, "alert_mem", &mut self.alert_mem),
+            (file.alert_temp, "alert_temp", &mut self.alert_temp),
+        ];
+        for (value, id, field) in thresholds {
+            if value.is_some() && !from_cli(id) {
+                *field = value;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `$XDG_CONFIG_HOME/peakmon/config.toml`, falling back to `~/.config`.
+fn default_path() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("peakmon").join("config.toml"))
+}
+
+fn parse_tab(name: &str) -> Result<Tab, String> {
+    Tab::from_name(name).ok_or_else(|| {
+        let names: Vec<String> = Tab::ALL.iter().map(|t| t.label().to_lowercase()).collect();
+        format!(
+            "unknown tab '{name}' (expected one of: {})",
+            names.join(", ")
+        )
+    })
+}
+
+/// Ollama keep-alive: whole seconds (negative means forever) or a number
+/// followed by s, m or h.
+fn parse_keep_alive(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let valid = value.parse::<i64>().is_ok()
+        || value
+            .strip_suffix(['s', 'm', 'h'])
+            .is_some_and(|n| n.parse::<u64>().is_ok());
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "invalid keep-alive '{value}' (expected seconds, e.g. 30m or 24h, or -1)"
+        ))
+    }
+}
+
+/// An http(s) URL, stored without a trailing slash.
+fn parse_ollama_host(url: &str) -> Result<String, String> {
+    let url = url.trim();
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(url.trim_end_matches('/').to_string())
+    } else {
+        Err(format!(
+            "invalid ollama-host '{url}' (expected a URL like {DEFAULT_OLLAMA_HOST})"
+        ))
+    }
+}
+
+fn parse_disk_full(value: &str) -> Result<f64, String> {
+    let percent: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid percentage '{value}'"))?;
+    check_disk_full(percent)
+}
+
+fn check_disk_full(percent: f64) -> Result<f64, String> {
+    if percent > 0.0 && percent <= 100.0 {
+        Ok(percent)
+    } else {
+        Err(format!("disk-full {percent} is outside 0..=100"))
+    }
+}
+
+fn parse_alpha(value: &str) -> Result<f64, String> {
+    let alpha: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid smoothing factor '{value}'"))?;
+    check_alpha(alpha)
+}
+
+fn check_alpha(alpha: f64) -> Result<f64, String> {
+    if alpha > 0.0 && alpha <= 1.0 {
+        Ok(alpha)
+    } else {
+        Err(format!("smooth-cpu {alpha} is outside 0..=1"))
+    }
+}
+
+fn parse_units(name: &str) -> Result<UnitMode, String> {
+    UnitMode::from_name(name)
+        .ok_or_else(|| format!("unknown units '{name}' (expected binary or decimal)"))
+}
+
+fn parse_temp_unit(name: &str) -> Result<TempUnit, String> {
+    TempUnit::from_name(name).ok_or_else(|| {
+        format!("unknown temperature unit '{name}' (expected celsius or fahrenheit)")
+    })
+}
+
+fn parse_cpu_scale(name: &str) -> Result<CpuScale, String> {
+    CpuScale::from_name(name)
+        .ok_or_else(|| format!("unknown cpu scale '{name}' (expected normalized or total)"))
+}
+
+fn parse_palette(name: &str) -> Result<PaletteKind, String> {
+    PaletteKind::from_name(name)
+        .ok_or_else(|| format!("unknown palette '{name}' (expected default or colorblind)"))
+}
+
+fn parse_column(name: &str) -> Result<ProcessColumn, String> {
+    ProcessColumn::from_key(name).ok_or_else(|| {
+        let keys: Vec<&str> = ProcessColumn::ALL.iter().map(|c| c.key()).collect();
+        format!(
+            "unknown column '{name}' (expected one of: {})",
+            keys.join(", ")
+        )
+    })
 }
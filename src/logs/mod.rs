@@ -1,5 +1,7 @@
 pub mod stream;
 
+use std::time::SystemTime;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum LogLevel {
     Default,
@@ -10,6 +12,25 @@ pub enum LogLevel {
 }
 
 impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Default,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Error,
+        LogLevel::Fault,
+    ];
+
+    /// Position in `ALL`, for per-level tallies.
+    pub fn index(&self) -> usize {
+        match self {
+            LogLevel::Default => 0,
+            LogLevel::Info => 1,
+            LogLevel::Debug => 2,
+            LogLevel::Error => 3,
+            LogLevel::Fault => 4,
+        }
+    }
+
     pub fn as_str(&self) -> &str {
         match self {
             LogLevel::Default => "DEFAULT",
@@ -24,7 +45,22 @@ impl LogLevel {
 #[derive(Clone, Debug)]
 pub struct LogEntry {
     pub timestamp: String,
+    /// `timestamp` parsed as local time; `None` if it didn't parse.
+    pub time: Option<SystemTime>,
     pub level: LogLevel,
     pub process: String,
     pub message: String,
 }
+
+impl LogEntry {
+    /// The entry as a single line in `log stream --style=compact` layout.
+    pub fn to_line(&self) -> String {
+        format!(
+            "{} [{}] {}: {}",
+            self.timestamp,
+            self.level.as_str(),
+            self.process,
+            self.message
+        )
+    }
+}
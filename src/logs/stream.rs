@@ -1,79 +1,166 @@
 use super::{LogEntry, LogLevel};
-use crate::util::contains_ignore_ascii_case;
+use crate::util::{contains_ignore_ascii_case, local_stamp, now_secs};
 use std::collections::VecDeque;
-use std::io::{BufRead, BufReader};
-use std::process::{Command, Stdio};
-use std::sync::mpsc;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, TryRecvError};
 use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A line from `log stream`: either a new entry or more text for the
+/// previous one (stack traces, multi-line messages).
+enum StreamLine {
+    Entry(LogEntry),
+    Continuation(String),
+    /// `log stream` couldn't start or exited with an error.
+    Failed(String),
+}
 
 pub struct LogStream {
     pub entries: VecDeque<LogEntry>,
     pub max_entries: usize,
+    /// Entries evicted from the front since the buffer filled up.
+    pub dropped: u64,
     pub auto_scroll: bool,
     pub level_filter: Option<LogLevel>,
     pub text_filter: String,
-    receiver: mpsc::Receiver<LogEntry>,
-    _handle: Option<thread::JoinHandle<()>>,
+    pub relative_time: bool,
+    /// Entries per level across the buffer, indexed by `LogLevel::index`.
+    pub level_counts: [usize; LogLevel::ALL.len()],
+    /// Stop auto-scrolling when an ERROR or FAULT arrives.
+    pub pause_on_error: bool,
+    /// An error paused the view and hasn't been acknowledged yet.
+    pub error_pending: bool,
+    /// Why `log stream` isn't delivering lines, when it failed.
+    pub error: Option<String>,
+    receiver: mpsc::Receiver<StreamLine>,
+    child: Option<Child>,
 }
 
 impl LogStream {
-    pub fn new() -> Self {
-        let (tx, rx) = mpsc::channel();
-        let max_entries = 5000;
-
-        let handle = thread::spawn(move || {
-            let child = Command::new("log")
-                .args(["stream", "--style=compact", "--level=default"])
-                .stdout(Stdio::piped())
-                .stderr(Stdio::null())
-                .spawn();
-
-            let child = match child {
-                Ok(c) => c,
-                Err(_) => return,
-            };
-
-            let stdout = match child.stdout {
-                Some(s) => s,
-                None => return,
-            };
-
-            let reader = BufReader::new(stdout);
-            for line in reader.lines() {
-                let line = match line {
-                    Ok(l) => l,
-                    Err(_) => continue,
-                };
-
-                if line.trim().is_empty() || line.starts_with("Filtering") {
-                    continue;
-                }
-
-                let entry = parse_log_line(&line);
-                if tx.send(entry).is_err() {
-                    break;
-                }
-            }
-        });
-
+    /// Start `log stream` in the background, keeping at most `max_entries`
+    /// lines. Entries own their strings, so memory grows linearly with the
+    /// buffer size; very large buffers also make filtering slower since it
+    /// scans the whole buffer every frame.
+    pub fn new(max_entries: usize) -> Self {
+        let (receiver, child) = spawn_reader();
         Self {
             entries: VecDeque::new(),
             max_entries,
+            dropped: 0,
             auto_scroll: true,
             level_filter: None,
             text_filter: String::new(),
-            receiver: rx,
-            _handle: Some(handle),
+            relative_time: false,
+            level_counts: [0; LogLevel::ALL.len()],
+            pause_on_error: false,
+            error_pending: false,
+            error: child.as_ref().err().cloned(),
+            receiver,
+            child: child.ok(),
+        }
+    }
+
+    /// Kill the current `log stream`, if any, and start a fresh one. The
+    /// buffer is kept; new lines append after the gap.
+    pub fn restart(&mut self) {
+        self.stop();
+        let (receiver, child) = spawn_reader();
+        self.receiver = receiver;
+        self.error = child.as_ref().err().cloned();
+        self.child = child.ok();
+    }
+
+    fn stop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
         }
     }
 
-    pub fn poll(&mut self) {
-        while let Ok(entry) = self.receiver.try_recv() {
+    /// Drain new entries. Returns true when an error just paused the view.
+    pub fn poll(&mut self) -> bool {
+        let mut paused = false;
+        loop {
+            let line = match self.receiver.try_recv() {
+                Ok(line) => line,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    // The reader thread ended: `log` exited or was killed
+                    if self.child.is_some() && self.error.is_none() {
+                        self.error = Some("log stream exited".to_string());
+                    }
+                    break;
+                }
+            };
+            let entry = match line {
+                StreamLine::Entry(entry) => entry,
+                StreamLine::Continuation(text) => {
+                    if let Some(last) = self.entries.back_mut() {
+                        last.message.push('\n');
+                        last.message.push_str(&text);
+                    }
+                    continue;
+                }
+                StreamLine::Failed(reason) => {
+                    self.error = Some(reason);
+                    continue;
+                }
+            };
+            if self.pause_on_error
+                && !self.error_pending
+                && matches!(entry.level, LogLevel::Error | LogLevel::Fault)
+            {
+                self.error_pending = true;
+                self.auto_scroll = false;
+                paused = true;
+            }
+            self.level_counts[entry.level.index()] += 1;
             self.entries.push_back(entry);
             if self.entries.len() > self.max_entries {
-                self.entries.pop_front();
+                if let Some(old) = self.entries.pop_front() {
+                    self.level_counts[old.level.index()] -= 1;
+                    self.dropped += 1;
+                }
             }
         }
+        paused
+    }
+
+    /// Drop every buffered entry. The reader thread keeps running, so new
+    /// lines keep arriving; filters and auto-scroll are left as they are.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.level_counts = [0; LogLevel::ALL.len()];
+        self.dropped = 0;
+        self.error_pending = false;
+    }
+
+    /// Whether new entries are now evicting the oldest ones.
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= self.max_entries
+    }
+
+    /// Index in `filtered_entries` of the newest ERROR or FAULT line.
+    pub fn last_error_index(&self) -> Option<usize> {
+        self.filtered_entries()
+            .iter()
+            .rposition(|e| matches!(e.level, LogLevel::Error | LogLevel::Fault))
+    }
+
+    pub fn toggle_pause_on_error(&mut self) {
+        self.pause_on_error = !self.pause_on_error;
+        if !self.pause_on_error {
+            self.error_pending = false;
+        }
+    }
+
+    /// Clear the pending error and resume following new lines.
+    pub fn acknowledge_error(&mut self) {
+        self.error_pending = false;
+        self.auto_scroll = true;
     }
 
     pub fn filtered_entries(&self) -> Vec<&LogEntry> {
@@ -111,6 +198,111 @@ impl LogStream {
     pub fn toggle_auto_scroll(&mut self) {
         self.auto_scroll = !self.auto_scroll;
     }
+
+    /// Write the current filtered view to `peakmon-logs-<timestamp>.log` in
+    /// the working directory.
+    pub fn export(&self) -> std::io::Result<PathBuf> {
+        let path = PathBuf::from(format!("peakmon-logs-{}.log", local_stamp(now_secs())));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for entry in self.filtered_entries() {
+            writeln!(writer, "{}", entry.to_line())?;
+        }
+        writer.flush()?;
+        Ok(path)
+    }
+
+    pub fn toggle_relative_time(&mut self) {
+        self.relative_time = !self.relative_time;
+    }
+}
+
+impl Drop for LogStream {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Start `log stream` with a thread forwarding its lines. The child is
+/// returned so it can be killed on restart, or why it couldn't start.
+fn spawn_reader() -> (mpsc::Receiver<StreamLine>, Result<Child, String>) {
+    let (tx, rx) = mpsc::channel();
+
+    // The unified log (and its `log` CLI) only exists on macOS
+    if !cfg!(target_os = "macos") {
+        return (rx, Err("log streaming needs macOS".to_string()));
+    }
+    let child = Command::new("log")
+        .args(["stream", "--style=compact", "--level=default"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(c) => c,
+        Err(e) => return (rx, Err(e.to_string())),
+    };
+    let (Some(stdout), stderr) = (child.stdout.take(), child.stderr.take()) else {
+        return (rx, Err("no output from log stream".to_string()));
+    };
+
+    // Drain stderr as it arrives so a chatty `log` can't fill the pipe and
+    // stall; keep the last line as the likely reason if the stream ends
+    let stderr_reader = stderr.map(|err| {
+        thread::spawn(move || {
+            BufReader::new(err)
+                .lines()
+                .map_while(Result::ok)
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .last()
+        })
+    });
+
+    thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        for line in reader.lines() {
+            let line = match line {
+                Ok(l) => l,
+                Err(_) => continue,
+            };
+
+            if line.trim().is_empty()
+                || line.starts_with("Filtering")
+                || line.starts_with("Timestamp")
+            {
+                continue;
+            }
+
+            let msg = if has_timestamp_prefix(&line) {
+                StreamLine::Entry(parse_log_line(&line))
+            } else {
+                StreamLine::Continuation(line)
+            };
+            if tx.send(msg).is_err() {
+                return;
+            }
+        }
+
+        // stdout closed: report why if `log` said (e.g. not permitted)
+        let reason = stderr_reader.and_then(|h| h.join().ok().flatten());
+        if let Some(reason) = reason {
+            let _ = tx.send(StreamLine::Failed(reason));
+        }
+    });
+
+    (rx, Ok(child))
+}
+
+/// Whether a line starts a new entry ("YYYY-MM-DD ...") rather than
+/// continuing the previous one.
+fn has_timestamp_prefix(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    bytes.len() >= 11
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b'-'
+        && bytes[5..7].iter().all(u8::is_ascii_digit)
+        && bytes[7] == b'-'
+        && bytes[8..10].iter().all(u8::is_ascii_digit)
+        && bytes[10] == b' '
 }
 
 fn parse_log_line(line: &str) -> LogEntry {
@@ -152,6 +344,7 @@ fn parse_log_line(line: &str) -> LogEntry {
         };
 
         LogEntry {
+            time: parse_timestamp(&timestamp),
             timestamp,
             level,
             process,
@@ -160,9 +353,48 @@ fn parse_log_line(line: &str) -> LogEntry {
     } else {
         LogEntry {
             timestamp: String::new(),
+            time: None,
             level: LogLevel::Default,
             process: String::new(),
             message: line.to_string(),
         }
     }
 }
+
+/// Parse a compact-format timestamp ("2024-01-01 12:00:00.000", local time).
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.split_once(' ')?;
+    let mut date_parts = date.splitn(3, '-').map(|p| p.parse::<i32>());
+    let (year, month, day) = (
+        date_parts.next()?.ok()?,
+        date_parts.next()?.ok()?,
+        date_parts.next()?.ok()?,
+    );
+
+    let (hms, frac) = time.split_once('.').unwrap_or((time, "0"));
+    let mut time_parts = hms.splitn(3, ':').map(|p| p.parse::<i32>());
+    let (hour, min, sec) = (
+        time_parts.next()?.ok()?,
+        time_parts.next()?.ok()?,
+        time_parts.next()?.ok()?,
+    );
+    // Fraction digits may be followed by a timezone offset
+    let frac_digits: String = frac.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let millis = format!("{frac_digits:0<3}")[..3].parse::<u64>().ok()?;
+
+    let secs = unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        tm.tm_year = year - 1900;
+        tm.tm_mon = month - 1;
+        tm.tm_mday = day;
+        tm.tm_hour = hour;
+        tm.tm_min = min;
+        tm.tm_sec = sec;
+        tm.tm_isdst = -1;
+        libc::mktime(&mut tm)
+    };
+    if secs < 0 {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::from_secs(secs as u64) + Duration::from_millis(millis))
+}
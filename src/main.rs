@@ -1,12 +1,15 @@
+mod alert;
 mod app;
 mod config;
 mod event;
 mod logs;
 mod metrics;
+mod record;
+mod snapshot;
+mod state;
 mod ui;
 mod util;
 
-use clap::Parser;
 use color_eyre::Result;
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
@@ -15,12 +18,37 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::path::Path;
+use std::time::Duration;
 
 use config::Config;
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let config = Config::parse();
+    let config = Config::load()?;
+    // Reports, snapshots and diffs format sizes too, so set units before them
+    util::set_unit_mode(config.units);
+
+    if let Some(path) = &config.report {
+        return write_report(path.as_deref(), &config);
+    }
+    if let Some(path) = &config.snapshot {
+        let metrics = sample_metrics(&config);
+        return snapshot::Snapshot::capture(&metrics, util::hostname(), util::now_secs())
+            .save(path);
+    }
+    if let Some([before, after]) = config.diff.as_deref() {
+        let before = snapshot::Snapshot::load(before)?;
+        let after = snapshot::Snapshot::load(after)?;
+        print!("{}", before.diff(&after));
+        return Ok(());
+    }
+
+    // Open the recording file before entering raw mode so errors print normally
+    let recorder = match &config.record {
+        Some(path) => Some(record::Recorder::create(path)?),
+        None => None,
+    };
 
     // Setup terminal
     enable_raw_mode()?;
@@ -31,9 +59,15 @@ fn main() -> Result<()> {
     terminal.clear()?;
 
     // Run app
-    let mut app = app::App::new(config.refresh_rate);
+    let mut app = app::App::new(&config, recorder);
     let result = app.run(&mut terminal);
 
+    // Reopen on this tab next time; failing to save isn't worth an error
+    let _ = state::State {
+        last_tab: Some(app.current_tab.label().to_lowercase()),
+    }
+    .save();
+
     // Restore terminal
     disable_raw_mode()?;
     execute!(
@@ -45,3 +79,25 @@ fn main() -> Result<()> {
 
     result
 }
+
+/// Sample every subsystem twice without starting the TUI; CPU usage and
+/// rates need an interval between refreshes.
+fn sample_metrics(config: &Config) -> metrics::MetricsCollector {
+    let mut metrics = metrics::MetricsCollector::new();
+    metrics.ai.ollama_host = config.ollama_host.clone();
+    metrics.refresh_all();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL.max(Duration::from_secs(1)));
+    metrics.refresh_all();
+    metrics
+}
+
+/// `--report`: write the Markdown report to `path`, or stdout.
+fn write_report(path: Option<&Path>, config: &Config) -> Result<()> {
+    let metrics = sample_metrics(config);
+    let report = metrics.to_markdown(&util::hostname(), config.temp_unit);
+    match path {
+        Some(path) => std::fs::write(path, report)?,
+        None => print!("{report}"),
+    }
+    Ok(())
+}
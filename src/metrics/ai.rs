@@ -2,11 +2,15 @@ use super::history::History;
 use super::process::ProcessInfo;
 use crate::util::{contains_ignore_ascii_case, format_bytes};
 use serde::Deserialize;
-use std::collections::HashMap;
-use std::sync::mpsc;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
 use std::time::Instant;
 
+/// Where Ollama listens unless `--ollama-host` says otherwise.
+pub const DEFAULT_OLLAMA_HOST: &str = "http://localhost:11434";
+
 // --- Service Detection ---
 
 #[derive(Clone)]
@@ -83,6 +87,13 @@ const AI_PROCESS_PATTERNS: &[&str] = &[
     "comfy",
 ];
 
+/// Whether a process name matches one of the known AI tool patterns.
+pub fn is_ai_process(name: &str) -> bool {
+    AI_PROCESS_PATTERNS
+        .iter()
+        .any(|pat| contains_ignore_ascii_case(name, pat))
+}
+
 // --- Ollama API types ---
 
 #[derive(Deserialize)]
@@ -125,6 +136,10 @@ pub enum PullStatus {
     Progress {
         status: String,
         percent: Option<f64>,
+        /// Download speed in bytes/s, once there's a second of samples
+        rate: Option<f64>,
+        /// Estimated seconds left on the layer being downloaded
+        eta_secs: Option<u64>,
     },
     Done,
     Error(String),
@@ -133,11 +148,59 @@ pub enum PullStatus {
 #[derive(Deserialize)]
 struct PullProgressLine {
     status: Option<String>,
+    digest: Option<String>,
     total: Option<u64>,
     completed: Option<u64>,
     error: Option<String>,
 }
 
+/// Smoothed download speed across a pull's progress lines. Ollama reports
+/// `completed` per layer, so it restarts from zero whenever a new layer
+/// (digest) begins.
+struct PullRate {
+    layer: Option<String>,
+    last_completed: u64,
+    window_start: Instant,
+    window_bytes: u64,
+    rate: Option<f64>,
+}
+
+impl PullRate {
+    fn new() -> Self {
+        Self {
+            layer: None,
+            last_completed: 0,
+            window_start: Instant::now(),
+            window_bytes: 0,
+            rate: None,
+        }
+    }
+
+    /// Record a progress line and return the current speed in bytes/s.
+    fn sample(&mut self, digest: Option<&str>, completed: u64) -> Option<f64> {
+        if digest != self.layer.as_deref() || completed < self.last_completed {
+            // New layer; a resumed one starts part-way, so don't count the
+            // bytes it already had
+            self.layer = digest.map(str::to_string);
+            self.last_completed = completed;
+        }
+        self.window_bytes += completed - self.last_completed;
+        self.last_completed = completed;
+
+        let elapsed = self.window_start.elapsed().as_secs_f64();
+        if elapsed >= 1.0 {
+            let current = self.window_bytes as f64 / elapsed;
+            self.rate = Some(match self.rate {
+                Some(prev) => prev * 0.7 + current * 0.3,
+                None => current,
+            });
+            self.window_start = Instant::now();
+            self.window_bytes = 0;
+        }
+        self.rate
+    }
+}
+
 // --- Chat Types ---
 
 #[derive(Clone)]
@@ -164,6 +227,51 @@ pub struct ChatMetrics {
     pub load_duration_ms: f64,
 }
 
+/// Fixed prompt for benchmarks, so runs are comparable across models and
+/// quantizations. Asks for a long-ish answer to get a stable tok/s figure.
+const BENCHMARK_PROMPT: &str =
+    "Explain in about 200 words how a CPU cache hierarchy works, from L1 to main memory.";
+
+/// Generation speed of a model across every response this session.
+#[derive(Clone, Default)]
+pub struct TpsStats {
+    pub min: f64,
+    pub max: f64,
+    total: f64,
+    pub count: u32,
+}
+
+impl TpsStats {
+    fn record(&mut self, tps: f64) {
+        if self.count == 0 {
+            self.min = tps;
+            self.max = tps;
+        } else {
+            self.min = self.min.min(tps);
+            self.max = self.max.max(tps);
+        }
+        self.total += tps;
+        self.count += 1;
+    }
+
+    pub fn avg(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total / self.count as f64
+        }
+    }
+}
+
+/// Outcome of one benchmark run against a model.
+#[derive(Clone)]
+pub struct BenchmarkResult {
+    pub tokens_per_sec: f64,
+    pub ttft_ms: f64,
+    pub total_duration_ms: f64,
+    pub gen_tokens: u64,
+}
+
 pub enum ChatToken {
     Token(String),
     FirstToken(String, f64), // token text, TTFT in ms
@@ -364,6 +472,8 @@ pub struct AiMetrics {
     pub pull_status: Option<PullStatus>,
     pub pull_model_name: Option<String>,
     pull_receiver: Option<mpsc::Receiver<PullStatus>>,
+    /// Set to make the pull thread hang up on ollama
+    pull_cancel: Option<Arc<AtomicBool>>,
     last_api_check: Option<Instant>,
     api_cache_secs: u64,
 
@@ -374,8 +484,23 @@ pub struct AiMetrics {
     pub chat_model: Option<String>,
     pub tps_history: History,
     pub last_tps: HashMap<String, f64>,
+    /// Min/avg/max tok/s per model, across chats and benchmarks
+    pub tps_stats: HashMap<String, TpsStats>,
+    /// Latest benchmark per model
+    pub benchmarks: HashMap<String, BenchmarkResult>,
+    /// The running generation is a benchmark; its output stays out of the chat
+    pub benchmarking: bool,
+    benchmark_ttft_ms: f64,
     chat_receiver: Option<mpsc::Receiver<ChatToken>>,
     pub chat_scroll: usize,
+    /// Most recent non-system messages sent with each chat turn
+    pub max_context_messages: usize,
+    /// `keep_alive` sent for pinned models ("-1" keeps them loaded forever)
+    pub keep_alive: String,
+    /// Models pinned in memory with `keep_alive`
+    pub pinned: HashSet<String>,
+    /// Base URL of the Ollama server, e.g. "http://localhost:11434"
+    pub ollama_host: String,
 
     // Search state
     pub search_results: Vec<SearchResult>,
@@ -383,10 +508,15 @@ pub struct AiMetrics {
     pub search_selected: usize,
     pub show_search: bool,
     search_receiver: Option<mpsc::Receiver<SearchStatus>>,
+
+    // Failures from background requests, drained by the app as toasts
+    error_tx: mpsc::Sender<String>,
+    error_rx: mpsc::Receiver<String>,
 }
 
 impl AiMetrics {
     pub fn new() -> Self {
+        let (error_tx, error_rx) = mpsc::channel();
         Self {
             services: Vec::new(),
             ollama_available: false,
@@ -401,6 +531,7 @@ impl AiMetrics {
             pull_status: None,
             pull_model_name: None,
             pull_receiver: None,
+            pull_cancel: None,
             last_api_check: None,
             api_cache_secs: 5,
 
@@ -410,17 +541,33 @@ impl AiMetrics {
             chat_model: None,
             tps_history: History::new(),
             last_tps: HashMap::new(),
+            tps_stats: HashMap::new(),
+            benchmarks: HashMap::new(),
+            benchmarking: false,
+            benchmark_ttft_ms: 0.0,
             chat_receiver: None,
             chat_scroll: 0,
+            max_context_messages: 20,
+            keep_alive: "-1".to_string(),
+            pinned: HashSet::new(),
+            ollama_host: DEFAULT_OLLAMA_HOST.to_string(),
 
             search_results: Vec::new(),
             search_status: None,
             search_selected: 0,
             show_search: false,
             search_receiver: None,
+
+            error_tx,
+            error_rx,
         }
     }
 
+    /// Full URL of the Ollama API endpoint `endpoint` on `ollama_host`.
+    fn api_url(&self, endpoint: &str) -> String {
+        format!("{}/api/{endpoint}", self.ollama_host.trim_end_matches('/'))
+    }
+
     pub fn update(&mut self, processes: &[ProcessInfo]) {
         self.detect_services(processes);
         self.filter_ai_processes(processes);
@@ -477,16 +624,8 @@ impl AiMetrics {
 
     fn filter_ai_processes(&mut self, processes: &[ProcessInfo]) {
         self.ai_processes.clear();
-        self.ai_processes.extend(
-            processes
-                .iter()
-                .filter(|p| {
-                    AI_PROCESS_PATTERNS
-                        .iter()
-                        .any(|pat| contains_ignore_ascii_case(&p.name, pat))
-                })
-                .cloned(),
-        );
+        self.ai_processes
+            .extend(processes.iter().filter(|p| is_ai_process(&p.name)).cloned());
 
         self.ai_processes.sort_unstable_by(|a, b| {
             b.cpu_usage
@@ -512,21 +651,21 @@ impl AiMetrics {
             .build();
 
         // Version
-        if let Ok(resp) = agent.get("http://localhost:11434/api/version").call() {
+        if let Ok(resp) = agent.get(&self.api_url("version")).call() {
             if let Ok(v) = resp.into_json::<OllamaVersion>() {
                 self.ollama_version = Some(v.version);
             }
         }
 
         // Tags (available models)
-        if let Ok(resp) = agent.get("http://localhost:11434/api/tags").call() {
+        if let Ok(resp) = agent.get(&self.api_url("tags")).call() {
             if let Ok(tags) = resp.into_json::<OllamaTagsResponse>() {
                 self.ollama_models = tags.models.unwrap_or_default();
             }
         }
 
         // Running models
-        if let Ok(resp) = agent.get("http://localhost:11434/api/ps").call() {
+        if let Ok(resp) = agent.get(&self.api_url("ps")).call() {
             if let Ok(ps) = resp.into_json::<OllamaPsResponse>() {
                 self.ollama_running = ps.models.unwrap_or_default();
             }
@@ -538,12 +677,17 @@ impl AiMetrics {
     pub fn start_pull(&mut self, model_name: String) {
         let (tx, rx) = mpsc::channel();
         self.pull_receiver = Some(rx);
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.pull_cancel = Some(Arc::clone(&cancel));
         self.pull_model_name = Some(model_name.clone());
         self.pull_status = Some(PullStatus::Progress {
             status: "Starting pull...".to_string(),
             percent: None,
+            rate: None,
+            eta_secs: None,
         });
 
+        let url = self.api_url("pull");
         thread::spawn(move || {
             let agent = ureq::AgentBuilder::new()
                 .timeout_connect(std::time::Duration::from_millis(5000))
@@ -552,16 +696,19 @@ impl AiMetrics {
 
             let body = serde_json::json!({ "name": model_name, "stream": true });
 
-            match agent
-                .post("http://localhost:11434/api/pull")
-                .send_json(&body)
-            {
+            match agent.post(&url).send_json(&body) {
                 Ok(resp) => {
                     let reader = resp.into_reader();
                     let buf_reader = std::io::BufReader::new(reader);
                     use std::io::BufRead;
                     let mut got_any_status = false;
+                    let mut pull_rate = PullRate::new();
                     for line in buf_reader.lines() {
+                        // Dropping the response closes the connection; ollama
+                        // keeps the partial blobs and resumes on the next pull
+                        if cancel.load(Ordering::Relaxed) {
+                            return;
+                        }
                         let line = match line {
                             Ok(l) => l,
                             Err(e) => {
@@ -578,19 +725,30 @@ impl AiMetrics {
                                 let _ = tx.send(PullStatus::Error(err));
                                 return;
                             }
-                            let percent = match (progress.total, progress.completed) {
-                                (Some(total), Some(completed)) if total > 0 => {
-                                    Some(completed as f64 / total as f64 * 100.0)
-                                }
-                                _ => None,
-                            };
+                            let (percent, rate, eta_secs) =
+                                match (progress.total, progress.completed) {
+                                    (Some(total), Some(completed)) if total > 0 => {
+                                        let rate =
+                                            pull_rate.sample(progress.digest.as_deref(), completed);
+                                        let eta = rate.filter(|&r| r > 0.0).map(|r| {
+                                            (total.saturating_sub(completed) as f64 / r) as u64
+                                        });
+                                        (Some(completed as f64 / total as f64 * 100.0), rate, eta)
+                                    }
+                                    _ => (None, None, None),
+                                };
                             let status = progress.status.unwrap_or_default();
                             got_any_status = true;
                             if status.contains("success") {
                                 let _ = tx.send(PullStatus::Done);
                                 return;
                             }
-                            let _ = tx.send(PullStatus::Progress { status, percent });
+                            let _ = tx.send(PullStatus::Progress {
+                                status,
+                                percent,
+                                rate,
+                                eta_secs,
+                            });
                         }
                     }
                     if got_any_status {
@@ -624,17 +782,33 @@ impl AiMetrics {
         });
     }
 
+    pub fn is_pulling(&self) -> bool {
+        matches!(self.pull_status, Some(PullStatus::Progress { .. }))
+    }
+
+    /// Stop the running pull. Already-downloaded layers stay on disk.
+    pub fn cancel_pull(&mut self) {
+        if let Some(cancel) = self.pull_cancel.take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
+        self.pull_receiver = None;
+        self.pull_status = None;
+        self.pull_model_name = None;
+    }
+
     pub fn delete_model(&mut self, model_name: &str) {
         let name = model_name.to_string();
+        let error_tx = self.error_tx.clone();
+        let url = self.api_url("delete");
         thread::spawn(move || {
             let agent = ureq::AgentBuilder::new()
                 .timeout_connect(std::time::Duration::from_millis(200))
                 .timeout_read(std::time::Duration::from_secs(10))
                 .build();
             let body = serde_json::json!({ "name": name });
-            let _ = agent
-                .delete("http://localhost:11434/api/delete")
-                .send_json(&body);
+            if let Err(e) = agent.delete(&url).send_json(&body) {
+                let _ = error_tx.send(format!("Delete {name} failed: {e}"));
+            }
         });
         // Remove from local list immediately
         self.ollama_models.retain(|m| m.name != model_name);
@@ -647,29 +821,61 @@ impl AiMetrics {
 
     pub fn load_model(&self, model_name: &str) {
         let name = model_name.to_string();
+        let keep_alive = self.keep_alive_for(model_name);
+        let url = self.api_url("generate");
         thread::spawn(move || {
             let agent = ureq::AgentBuilder::new()
                 .timeout_connect(std::time::Duration::from_millis(200))
                 .timeout_read(std::time::Duration::from_secs(60))
                 .build();
-            let body = serde_json::json!({ "model": name, "prompt": "" });
-            let _ = agent
-                .post("http://localhost:11434/api/generate")
-                .send_json(&body);
+            let mut body = serde_json::json!({ "model": name, "prompt": "" });
+            if let Some(keep_alive) = keep_alive {
+                body["keep_alive"] = keep_alive;
+            }
+            let _ = agent.post(&url).send_json(&body);
         });
     }
 
-    pub fn unload_model(&self, model_name: &str) {
+    /// Pin `model_name` in memory with the configured `keep_alive`, or unpin
+    /// it back to the server's default. Either way it's (re)loaded so the new
+    /// keep-alive applies now. Returns whether the model is now pinned.
+    pub fn toggle_pin(&mut self, model_name: &str) -> bool {
+        let pinned = if self.pinned.remove(model_name) {
+            false
+        } else {
+            self.pinned.insert(model_name.to_string());
+            true
+        };
+        self.load_model(model_name);
+        pinned
+    }
+
+    /// The `keep_alive` value to send with requests for `model_name`: the
+    /// configured one when pinned, otherwise none (the server default).
+    /// Ollama resets a model's keep-alive on every request, so chats must
+    /// send it too.
+    fn keep_alive_for(&self, model_name: &str) -> Option<serde_json::Value> {
+        if !self.pinned.contains(model_name) {
+            return None;
+        }
+        // Plain numbers are seconds and must be sent as JSON numbers
+        Some(match self.keep_alive.parse::<i64>() {
+            Ok(secs) => serde_json::Value::from(secs),
+            Err(_) => serde_json::Value::from(self.keep_alive.clone()),
+        })
+    }
+
+    pub fn unload_model(&mut self, model_name: &str) {
+        self.pinned.remove(model_name);
         let name = model_name.to_string();
+        let url = self.api_url("generate");
         thread::spawn(move || {
             let agent = ureq::AgentBuilder::new()
                 .timeout_connect(std::time::Duration::from_millis(200))
                 .timeout_read(std::time::Duration::from_secs(5))
                 .build();
             let body = serde_json::json!({ "model": name, "keep_alive": 0 });
-            let _ = agent
-                .post("http://localhost:11434/api/generate")
-                .send_json(&body);
+            let _ = agent.post(&url).send_json(&body);
         });
     }
 
@@ -695,6 +901,10 @@ impl AiMetrics {
             while let Ok(status) = rx.try_recv() {
                 match &status {
                     PullStatus::Done | PullStatus::Error(_) => {
+                        if let PullStatus::Error(err) = &status {
+                            let name = self.pull_model_name.as_deref().unwrap_or("model");
+                            let _ = self.error_tx.send(format!("Pull {name} failed: {err}"));
+                        }
                         self.pull_status = Some(status);
                         self.pull_receiver = None;
                         self.last_api_check = None; // Force refresh
@@ -716,10 +926,11 @@ impl AiMetrics {
     }
 
     pub fn model_status(&self, model_name: &str) -> &str {
-        if self.ollama_running.iter().any(|r| r.name == model_name) {
-            "Loaded"
-        } else {
-            "Ready"
+        let loaded = self.ollama_running.iter().any(|r| r.name == model_name);
+        match (loaded, self.pinned.contains(model_name)) {
+            (true, true) => "Pinned",
+            (true, false) => "Loaded",
+            (false, _) => "Ready",
         }
     }
 
@@ -731,6 +942,7 @@ impl AiMetrics {
         self.chat_status = ChatStatus::Generating;
         self.chat_model = Some(model.to_string());
 
+        let keep_alive = self.keep_alive_for(model);
         let model = model.to_string();
         let msgs: Vec<serde_json::Value> = messages
             .iter()
@@ -742,25 +954,26 @@ impl AiMetrics {
             })
             .collect();
 
+        let url = self.api_url("chat");
         thread::spawn(move || {
             let agent = ureq::AgentBuilder::new()
                 .timeout_connect(std::time::Duration::from_millis(2000))
                 .timeout_read(std::time::Duration::from_secs(300))
                 .build();
 
-            let body = serde_json::json!({
+            let mut body = serde_json::json!({
                 "model": model,
                 "messages": msgs,
                 "stream": true,
             });
+            if let Some(keep_alive) = keep_alive {
+                body["keep_alive"] = keep_alive;
+            }
 
             let start_time = Instant::now();
             let mut first_token = true;
 
-            match agent
-                .post("http://localhost:11434/api/chat")
-                .send_json(&body)
-            {
+            match agent.post(&url).send_json(&body) {
                 Ok(resp) => {
                     let reader = resp.into_reader();
                     let buf_reader = std::io::BufReader::new(reader);
@@ -835,6 +1048,78 @@ impl AiMetrics {
         });
     }
 
+    /// How many of the oldest non-system messages fall outside the context
+    /// window. The window always starts on a user turn.
+    pub fn dropped_messages(&self) -> usize {
+        let conversation: Vec<_> = self
+            .chat_messages
+            .iter()
+            .filter(|m| m.role != "system")
+            .collect();
+        let mut dropped = conversation.len().saturating_sub(self.max_context_messages);
+        while dropped > 0 && conversation.get(dropped).is_some_and(|m| m.role != "user") {
+            dropped += 1;
+        }
+        dropped
+    }
+
+    /// The messages to send for the next turn: any system prompt plus the
+    /// most recent `max_context_messages`, so long chats stay within the
+    /// model's context window.
+    pub fn context_messages(&self) -> Vec<ChatMessage> {
+        let dropped = self.dropped_messages();
+        let mut seen = 0;
+        self.chat_messages
+            .iter()
+            .filter(|m| {
+                if m.role == "system" {
+                    return true;
+                }
+                seen += 1;
+                seen > dropped
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Run the fixed benchmark prompt against `model`, recording the result
+    /// in `benchmarks` instead of the chat.
+    pub fn start_benchmark(&mut self, model: &str) {
+        if self.chat_status == ChatStatus::Generating {
+            return;
+        }
+        self.benchmarking = true;
+        self.benchmark_ttft_ms = 0.0;
+        let prompt = ChatMessage {
+            role: "user".to_string(),
+            content: BENCHMARK_PROMPT.to_string(),
+        };
+        self.start_chat(model, &[prompt]);
+    }
+
+    /// Note a finished response's speed. Responses with no generated tokens
+    /// (errors, empty replies) would drag the average down, so they're skipped.
+    fn record_tps(&mut self, model: &str, tps: f64) {
+        self.last_tps.insert(model.to_string(), tps);
+        if tps > 0.0 {
+            self.tps_stats
+                .entry(model.to_string())
+                .or_default()
+                .record(tps);
+        }
+    }
+
+    /// Leaderboard of benchmark results, fastest first.
+    pub fn benchmark_ranking(&self) -> Vec<(&String, &BenchmarkResult)> {
+        let mut ranking: Vec<_> = self.benchmarks.iter().collect();
+        ranking.sort_by(|a, b| {
+            b.1.tokens_per_sec
+                .partial_cmp(&a.1.tokens_per_sec)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        ranking
+    }
+
     fn poll_chat(&mut self) {
         if self.chat_receiver.is_none() {
             return;
@@ -842,6 +1127,42 @@ impl AiMetrics {
 
         let rx = self.chat_receiver.as_ref().unwrap();
         loop {
+            if self.benchmarking {
+                match rx.try_recv() {
+                    Ok(ChatToken::Token(_)) => continue,
+                    Ok(ChatToken::FirstToken(_, ttft)) => {
+                        self.benchmark_ttft_ms = ttft;
+                        continue;
+                    }
+                    Ok(ChatToken::Done(metrics)) => {
+                        if let Some(model) = self.chat_model.clone() {
+                            self.record_tps(&model, metrics.tokens_per_sec);
+                            self.benchmarks.insert(
+                                model.clone(),
+                                BenchmarkResult {
+                                    tokens_per_sec: metrics.tokens_per_sec,
+                                    ttft_ms: self.benchmark_ttft_ms,
+                                    total_duration_ms: metrics.total_duration_ms,
+                                    gen_tokens: metrics.gen_tokens,
+                                },
+                            );
+                        }
+                        self.chat_status = ChatStatus::Done;
+                    }
+                    Ok(ChatToken::Error(err)) => {
+                        let _ = self.error_tx.send(format!("Benchmark failed: {err}"));
+                        self.chat_status = ChatStatus::Idle;
+                    }
+                    Err(mpsc::TryRecvError::Empty) => return,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        self.chat_status = ChatStatus::Idle;
+                    }
+                }
+                self.benchmarking = false;
+                self.chat_receiver = None;
+                return;
+            }
+
             match rx.try_recv() {
                 Ok(ChatToken::Token(text)) => {
                     if let Some(last) = self.chat_messages.last_mut() {
@@ -884,9 +1205,8 @@ impl AiMetrics {
                         ..metrics
                     };
 
-                    if let Some(ref model) = self.chat_model {
-                        self.last_tps
-                            .insert(model.clone(), final_metrics.tokens_per_sec);
+                    if let Some(model) = self.chat_model.clone() {
+                        self.record_tps(&model, final_metrics.tokens_per_sec);
                     }
                     self.tps_history.push(final_metrics.tokens_per_sec);
                     self.chat_metrics = Some(final_metrics);
@@ -913,6 +1233,7 @@ impl AiMetrics {
 
     pub fn cancel_chat(&mut self) {
         self.chat_receiver = None;
+        self.benchmarking = false;
         if self.chat_status == ChatStatus::Generating {
             self.chat_status = ChatStatus::Idle;
         }
@@ -988,6 +1309,7 @@ impl AiMetrics {
                 self.search_receiver = None;
             }
             Ok(SearchStatus::Error(err)) => {
+                let _ = self.error_tx.send(err.clone());
                 self.search_status = Some(err);
                 self.search_receiver = None;
             }
@@ -998,6 +1320,11 @@ impl AiMetrics {
         }
     }
 
+    /// Failures reported by background requests since the last call.
+    pub fn take_errors(&self) -> Vec<String> {
+        self.error_rx.try_iter().collect()
+    }
+
     pub fn search_select_next(&mut self) {
         let count = self.search_results.len();
         if count > 0 {
@@ -1009,6 +1336,13 @@ impl AiMetrics {
         self.search_selected = self.search_selected.saturating_sub(1);
     }
 
+    /// The ollama.com library page of the selected search result.
+    pub fn selected_search_url(&self) -> Option<String> {
+        self.search_results
+            .get(self.search_selected)
+            .map(|r| format!("https://ollama.com/library/{}", r.name))
+    }
+
     pub fn selected_search_model(&self) -> Option<String> {
         self.search_results.get(self.search_selected).map(|r| {
             // Append the first (smallest) size tag so the pull targets a specific variant
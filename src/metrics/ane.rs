@@ -0,0 +1,106 @@
+use super::history::History;
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::time::Instant;
+
+/// Seconds between `powermetrics` samples.
+const SAMPLE_INTERVAL_SECS: u64 = 5;
+
+/// Apple Neural Engine power draw, read from `powermetrics`. Many models
+/// run on the ANE rather than the GPU, so this tells ANE-accelerated
+/// inference apart from GPU or CPU work.
+pub struct AneMetrics {
+    /// `powermetrics` only runs as root; false otherwise or once it fails.
+    pub available: bool,
+    /// Latest sample, `None` until the first one arrives.
+    pub ane_power_mw: Option<f64>,
+    pub power_history: History,
+    /// Peak power seen this session, for scaling the sparkline.
+    pub peak_power_mw: f64,
+    /// A sample in flight; polled rather than waited on, since
+    /// `powermetrics` blocks for the whole sample window.
+    sampler: Option<Child>,
+    last_sample: Option<Instant>,
+}
+
+impl AneMetrics {
+    pub fn new() -> Self {
+        Self {
+            available: unsafe { libc::geteuid() } == 0,
+            ane_power_mw: None,
+            power_history: History::new(),
+            peak_power_mw: 0.0,
+            sampler: None,
+            last_sample: None,
+        }
+    }
+
+    pub fn update(&mut self) {
+        if !self.available {
+            return;
+        }
+
+        if let Some(ref mut child) = self.sampler {
+            match child.try_wait() {
+                Ok(None) => return,
+                Ok(Some(status)) => {
+                    let mut output = String::new();
+                    if let Some(mut stdout) = child.stdout.take() {
+                        let _ = stdout.read_to_string(&mut output);
+                    }
+                    self.sampler = None;
+                    match parse_ane_power(&output) {
+                        Some(mw) if status.success() => self.record(mw),
+                        // No ANE sampler on this machine or OS version
+                        _ => self.available = false,
+                    }
+                }
+                Err(_) => {
+                    self.sampler = None;
+                    self.available = false;
+                }
+            }
+            return;
+        }
+
+        let due = self
+            .last_sample
+            .is_none_or(|t| t.elapsed().as_secs() >= SAMPLE_INTERVAL_SECS);
+        if !due {
+            return;
+        }
+        self.last_sample = Some(Instant::now());
+        match Command::new("powermetrics")
+            .args(["--samplers", "ane_power", "-i", "500", "-n", "1"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => self.sampler = Some(child),
+            Err(_) => self.available = false,
+        }
+    }
+
+    fn record(&mut self, mw: f64) {
+        self.ane_power_mw = Some(mw);
+        self.peak_power_mw = self.peak_power_mw.max(mw);
+        self.power_history.push(mw);
+    }
+}
+
+impl Drop for AneMetrics {
+    fn drop(&mut self) {
+        if let Some(ref mut child) = self.sampler {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// The `ANE Power: 123 mW` line from `powermetrics` output.
+fn parse_ane_power(output: &str) -> Option<f64> {
+    output.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("ANE Power:")?;
+        value.trim().trim_end_matches("mW").trim().parse().ok()
+    })
+}
@@ -1,24 +1,104 @@
 use super::history::History;
+use crate::util::format_percent;
+use std::process::Command;
+use std::time::Instant;
 use sysinfo::System;
 
+/// How aggregate CPU usage is shown: averaged across cores (0–100%), or
+/// summed like `top` where 350% means three and a half cores busy.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CpuScale {
+    Normalized,
+    Total,
+}
+
+impl CpuScale {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "normalized" => Some(CpuScale::Normalized),
+            "total" => Some(CpuScale::Total),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CpuScale::Normalized => "normalized",
+            CpuScale::Total => "total",
+        }
+    }
+}
+
+/// Weight of the newest sample in the smoothed aggregate unless
+/// `--smooth-cpu` sets one.
+pub const DEFAULT_SMOOTHING_ALPHA: f64 = 0.3;
+
+/// Share of CPU time spent in each mode since the previous sample, in percent.
+#[derive(Clone, Copy, Default)]
+pub struct CpuBreakdown {
+    pub user: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub nice: f64,
+}
+
 pub struct CpuMetrics {
     pub aggregate_usage: f64,
+    /// Exponential moving average of `aggregate_usage`
+    pub smoothed_usage: f64,
+    /// Plot `smoothed_history` instead of the raw samples; the numeric
+    /// readout always stays instantaneous
+    pub smooth: bool,
+    /// Weight of the newest sample in `smoothed_usage`, in (0, 1]
+    pub smoothing_alpha: f64,
     pub per_core_usage: Vec<f64>,
     pub aggregate_history: History,
+    /// `smoothed_usage` per sample, kept alongside the raw history so
+    /// toggling `smooth` redraws the whole window
+    pub smoothed_history: History,
     pub per_core_history: Vec<History>,
     pub core_count: usize,
     pub load_avg: [f64; 3],
+    pub breakdown: CpuBreakdown,
+    /// CPU speed limit from `pmset -g therm`, in percent of full speed;
+    /// `None` until one has been recorded
+    pub speed_limit: Option<u32>,
+    /// Scale for the aggregate usage label; histories stay normalized
+    pub scale: CpuScale,
+    prev_ticks: Option<[u32; CPU_STATE_MAX]>,
+    last_therm: Option<Instant>,
 }
 
 impl CpuMetrics {
     pub fn new(core_count: usize) -> Self {
         Self {
             aggregate_usage: 0.0,
+            smoothed_usage: 0.0,
+            smooth: false,
+            smoothing_alpha: DEFAULT_SMOOTHING_ALPHA,
             per_core_usage: vec![0.0; core_count],
             aggregate_history: History::new(),
+            smoothed_history: History::new(),
             per_core_history: (0..core_count).map(|_| History::new()).collect(),
             core_count,
             load_avg: [0.0; 3],
+            breakdown: CpuBreakdown::default(),
+            speed_limit: None,
+            scale: CpuScale::Normalized,
+            prev_ticks: None,
+            last_therm: None,
+        }
+    }
+
+    /// Aggregate usage in the active scale, e.g. "43.7%" or "350% of 800%".
+    pub fn usage_label(&self) -> String {
+        match self.scale {
+            CpuScale::Normalized => format_percent(self.aggregate_usage),
+            CpuScale::Total => format!(
+                "{:.0}% of {}%",
+                self.aggregate_usage * self.core_count as f64,
+                self.core_count * 100
+            ),
         }
     }
 
@@ -49,7 +129,15 @@ impl CpuMetrics {
         } else {
             self.per_core_usage.iter().sum::<f64>() / self.per_core_usage.len() as f64
         };
+        // Kept up to date even while off so toggling it on is already settled
+        self.smoothed_usage = if self.aggregate_history.is_empty() {
+            self.aggregate_usage
+        } else {
+            self.smoothing_alpha * self.aggregate_usage
+                + (1.0 - self.smoothing_alpha) * self.smoothed_usage
+        };
         self.aggregate_history.push(self.aggregate_usage);
+        self.smoothed_history.push(self.smoothed_usage);
 
         // Load average
         let mut loadavg = [0.0_f64; 3];
@@ -57,5 +145,107 @@ impl CpuMetrics {
             libc::getloadavg(loadavg.as_mut_ptr(), 3);
         }
         self.load_avg = loadavg;
+
+        // User/system/idle/nice split from host-wide tick counters
+        if let Some(ticks) = get_cpu_load_ticks() {
+            if let Some(prev) = self.prev_ticks {
+                let delta: Vec<f64> = ticks
+                    .iter()
+                    .zip(prev.iter())
+                    .map(|(&now, &before)| now.wrapping_sub(before) as f64)
+                    .collect();
+                let total: f64 = delta.iter().sum();
+                if total > 0.0 {
+                    self.breakdown = CpuBreakdown {
+                        user: delta[CPU_STATE_USER] / total * 100.0,
+                        system: delta[CPU_STATE_SYSTEM] / total * 100.0,
+                        idle: delta[CPU_STATE_IDLE] / total * 100.0,
+                        nice: delta[CPU_STATE_NICE] / total * 100.0,
+                    };
+                }
+            }
+            self.prev_ticks = Some(ticks);
+        }
+
+        // The speed limit follows thermal state, which shifts over seconds
+        // rather than per tick, so a 5 s poll keeps the THROTTLED badge
+        // current without forking pmset on every refresh
+        let should_refresh = self.last_therm.is_none_or(|t| t.elapsed().as_secs() >= 5);
+        if should_refresh {
+            self.speed_limit = read_speed_limit();
+            self.last_therm = Some(Instant::now());
+        }
     }
+
+    /// The aggregate history to plot: smoothed or raw per `smooth`.
+    pub fn plotted_history(&self) -> &History {
+        if self.smooth {
+            &self.smoothed_history
+        } else {
+            &self.aggregate_history
+        }
+    }
+
+    /// The CPU is being held below full speed for thermal or power reasons.
+    pub fn is_throttled(&self) -> bool {
+        self.speed_limit.is_some_and(|limit| limit < 100)
+    }
+}
+
+/// `CPU_Speed_Limit` from `pmset -g therm`. The line only appears once the
+/// OS has recorded a thermal or power limit.
+fn read_speed_limit() -> Option<u32> {
+    let output = Command::new("pmset").args(["-g", "therm"]).output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if key.trim() == "CPU_Speed_Limit" {
+            value.trim().parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+// --- Native macOS CPU load ticks via host_statistics ---
+
+#[cfg(target_os = "macos")]
+const HOST_CPU_LOAD_INFO: i32 = 3;
+const CPU_STATE_USER: usize = 0;
+const CPU_STATE_SYSTEM: usize = 1;
+const CPU_STATE_IDLE: usize = 2;
+const CPU_STATE_NICE: usize = 3;
+const CPU_STATE_MAX: usize = 4;
+#[cfg(target_os = "macos")]
+const KERN_SUCCESS: i32 = 0;
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn mach_host_self() -> u32;
+    fn host_statistics(host: u32, flavor: i32, info: *mut i32, count: *mut u32) -> i32;
+}
+
+#[cfg(target_os = "macos")]
+fn get_cpu_load_ticks() -> Option<[u32; CPU_STATE_MAX]> {
+    unsafe {
+        let mut ticks = [0u32; CPU_STATE_MAX];
+        let mut count = CPU_STATE_MAX as u32;
+        let ret = host_statistics(
+            mach_host_self(),
+            HOST_CPU_LOAD_INFO,
+            ticks.as_mut_ptr() as *mut i32,
+            &mut count,
+        );
+        if ret == KERN_SUCCESS {
+            Some(ticks)
+        } else {
+            None
+        }
+    }
+}
+
+/// No user/system breakdown elsewhere; the aggregate still comes from sysinfo.
+#[cfg(not(target_os = "macos"))]
+fn get_cpu_load_ticks() -> Option<[u32; CPU_STATE_MAX]> {
+    None
 }
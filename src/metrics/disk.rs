@@ -1,5 +1,8 @@
 use super::history::History;
+use super::per_second;
 use std::collections::HashMap;
+use std::process::Command;
+use std::time::Instant;
 use sysinfo::Disks;
 
 pub struct DiskInfo {
@@ -10,6 +13,22 @@ pub struct DiskInfo {
     pub used_percent: f64,
     pub read_rate: f64,
     pub write_rate: f64,
+    /// Used space is above [`DiskMetrics::full_threshold`].
+    pub nearly_full: bool,
+}
+
+impl DiskInfo {
+    /// Combined read + write throughput, used for sorting by I/O.
+    pub fn io_rate(&self) -> f64 {
+        self.read_rate + self.write_rate
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DiskSortField {
+    Size,
+    Used,
+    Io,
 }
 
 pub struct DiskIoSnapshot {
@@ -23,8 +42,20 @@ pub struct DiskMetrics {
     pub write_rate: f64,
     pub read_history: History,
     pub write_history: History,
+    /// Read/write operations per second across all block devices; `None`
+    /// when the driver statistics aren't available.
+    pub read_iops: Option<f64>,
+    pub write_iops: Option<f64>,
+    /// `None` keeps the order the filesystem reports volumes in.
+    pub sort_field: Option<DiskSortField>,
+    pub sort_ascending: bool,
+    /// Used percentage above which a volume is flagged as nearly full
+    pub full_threshold: f64,
     prev_snapshot: Option<DiskIoSnapshot>,
     prev_per_disk: HashMap<String, DiskIoSnapshot>,
+    /// Operation counts at the last IOPS sample, and when it was taken
+    prev_ops: Option<(u64, u64, Instant)>,
+    last_update: Option<Instant>,
 }
 
 impl DiskMetrics {
@@ -35,12 +66,23 @@ impl DiskMetrics {
             write_rate: 0.0,
             read_history: History::new(),
             write_history: History::new(),
+            read_iops: None,
+            write_iops: None,
+            sort_field: None,
+            sort_ascending: false,
+            full_threshold: 90.0,
             prev_snapshot: None,
             prev_per_disk: HashMap::new(),
+            prev_ops: None,
+            last_update: None,
         }
     }
 
     pub fn update(&mut self, sysinfo_disks: &Disks) {
+        let now = Instant::now();
+        let elapsed = self.last_update.map(|t| now.duration_since(t));
+        self.last_update = Some(now);
+
         self.disks.clear();
         let mut total_read: u64 = 0;
         let mut total_written: u64 = 0;
@@ -64,8 +106,8 @@ impl DiskMetrics {
                 continue;
             }
 
-            let disk_read = disk.usage().read_bytes;
-            let disk_written = disk.usage().written_bytes;
+            let disk_read = disk.usage().total_read_bytes;
+            let disk_written = disk.usage().total_written_bytes;
 
             let display_name = if name.is_empty() { mount.clone() } else { name };
 
@@ -73,8 +115,8 @@ impl DiskMetrics {
             let (per_read_rate, per_write_rate) =
                 if let Some(prev) = self.prev_per_disk.get(&display_name) {
                     (
-                        disk_read.saturating_sub(prev.read_bytes) as f64,
-                        disk_written.saturating_sub(prev.written_bytes) as f64,
+                        per_second(disk_read.saturating_sub(prev.read_bytes), elapsed),
+                        per_second(disk_written.saturating_sub(prev.written_bytes), elapsed),
                     )
                 } else {
                     (0.0, 0.0)
@@ -96,6 +138,7 @@ impl DiskMetrics {
                 used_percent: used_pct,
                 read_rate: per_read_rate,
                 write_rate: per_write_rate,
+                nearly_full: used_pct > self.full_threshold,
             });
 
             total_read = total_read.wrapping_add(disk_read);
@@ -103,6 +146,7 @@ impl DiskMetrics {
         }
 
         self.prev_per_disk = new_per_disk;
+        self.sort();
 
         let current = DiskIoSnapshot {
             read_bytes: total_read,
@@ -110,12 +154,125 @@ impl DiskMetrics {
         };
 
         if let Some(prev) = &self.prev_snapshot {
-            self.read_rate = total_read.saturating_sub(prev.read_bytes) as f64;
-            self.write_rate = total_written.saturating_sub(prev.written_bytes) as f64;
+            self.read_rate = per_second(total_read.saturating_sub(prev.read_bytes), elapsed);
+            self.write_rate = per_second(total_written.saturating_sub(prev.written_bytes), elapsed);
         }
 
         self.prev_snapshot = Some(current);
         self.read_history.push(self.read_rate);
         self.write_history.push(self.write_rate);
     }
+
+    /// Sample IOPS from the block-storage drivers. Kept out of `update`
+    /// because it forks `ioreg`, and only the Disk tab shows the result.
+    pub fn update_iops(&mut self) {
+        let now = Instant::now();
+        let ops = query_io_operations();
+        (self.read_iops, self.write_iops) = match (ops, self.prev_ops) {
+            (Some((read, write)), Some((prev_read, prev_write, at))) => {
+                let elapsed = Some(now.duration_since(at));
+                (
+                    Some(per_second(read.saturating_sub(prev_read), elapsed)),
+                    Some(per_second(write.saturating_sub(prev_write), elapsed)),
+                )
+            }
+            _ => (None, None),
+        };
+        self.prev_ops = ops.map(|(read, write)| (read, write, now));
+    }
+
+    /// Forget the last IOPS sample so returning to the Disk tab doesn't
+    /// average over the time it was hidden.
+    pub fn pause_iops(&mut self) {
+        self.prev_ops = None;
+    }
+
+    /// Volumes over the nearly-full threshold.
+    pub fn nearly_full(&self) -> impl Iterator<Item = &DiskInfo> {
+        self.disks.iter().filter(|d| d.nearly_full)
+    }
+
+    pub fn set_sort_field(&mut self, field: DiskSortField) {
+        if self.sort_field == Some(field) {
+            self.sort_ascending = !self.sort_ascending;
+        } else {
+            self.sort_field = Some(field);
+            self.sort_ascending = false;
+        }
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        let Some(field) = self.sort_field else {
+            return;
+        };
+        let ascending = self.sort_ascending;
+        let key = |d: &DiskInfo| match field {
+            DiskSortField::Size => d.total_space as f64,
+            DiskSortField::Used => d.used_percent,
+            DiskSortField::Io => d.io_rate(),
+        };
+        self.disks.sort_by(|a, b| {
+            let cmp = key(a)
+                .partial_cmp(&key(b))
+                .unwrap_or(std::cmp::Ordering::Equal);
+            if ascending {
+                cmp
+            } else {
+                cmp.reverse()
+            }
+        });
+    }
+
+    /// Total and available space across all volumes. APFS volumes in one
+    /// container all report the container's size and free space, so volumes
+    /// with identical figures are only counted once.
+    pub fn space_totals(&self) -> (u64, u64) {
+        let mut seen = Vec::new();
+        let mut total = 0;
+        let mut available = 0;
+        for disk in &self.disks {
+            let key = (disk.total_space, disk.available_space);
+            if !seen.contains(&key) {
+                seen.push(key);
+                total += disk.total_space;
+                available += disk.available_space;
+            }
+        }
+        (total, available)
+    }
+}
+
+/// Cumulative (read, write) operation counts summed over every block
+/// storage driver, from the `Statistics` dictionary ioreg reports.
+fn query_io_operations() -> Option<(u64, u64)> {
+    let output = Command::new("ioreg")
+        .args(["-r", "-c", "IOBlockStorageDriver", "-w", "0"])
+        .output()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    let mut found = false;
+    let mut read = 0u64;
+    let mut write = 0u64;
+    for line in text.lines().filter(|l| l.contains("\"Statistics\" =")) {
+        if let Some(n) = extract_number(line, "\"Operations (Read)\"=") {
+            read += n;
+            found = true;
+        }
+        if let Some(n) = extract_number(line, "\"Operations (Write)\"=") {
+            write += n;
+            found = true;
+        }
+    }
+    found.then_some((read, write))
+}
+
+fn extract_number(text: &str, prefix: &str) -> Option<u64> {
+    let start = text.find(prefix)? + prefix.len();
+    let rest = &text[start..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
 }
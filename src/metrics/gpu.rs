@@ -1,7 +1,110 @@
 use super::history::History;
+use std::collections::HashMap;
 use std::process::Command;
+use std::time::Instant;
 
-pub struct GpuMetrics {
+#[derive(Clone, Copy, PartialEq)]
+pub enum GpuVendor {
+    Apple,
+    Amd,
+    Intel,
+    Unknown,
+}
+
+impl GpuVendor {
+    /// Guess the vendor from the accelerator's IOKit class name.
+    fn from_class(class: &str) -> Self {
+        if class.starts_with("AGX") {
+            GpuVendor::Apple
+        } else if class.starts_with("AMD") {
+            GpuVendor::Amd
+        } else if class.starts_with("Intel") || class.starts_with("IGAccel") {
+            GpuVendor::Intel
+        } else {
+            GpuVendor::Unknown
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GpuVendor::Apple => "Apple",
+            GpuVendor::Amd => "AMD",
+            GpuVendor::Intel => "Intel",
+            GpuVendor::Unknown => "Unknown",
+        }
+    }
+
+    /// ioreg keys this vendor's driver reports. Each list is tried in order.
+    fn keys(&self) -> &'static GpuKeys {
+        match self {
+            GpuVendor::Apple => &APPLE_KEYS,
+            GpuVendor::Amd => &AMD_KEYS,
+            GpuVendor::Intel => &INTEL_KEYS,
+            GpuVendor::Unknown => &UNKNOWN_KEYS,
+        }
+    }
+}
+
+struct GpuKeys {
+    utilization: &'static [&'static str],
+    renderer: &'static [&'static str],
+    tiler: &'static [&'static str],
+    in_use_memory: &'static [&'static str],
+    alloc_memory: &'static [&'static str],
+    total_memory: &'static [&'static str],
+}
+
+const APPLE_KEYS: GpuKeys = GpuKeys {
+    utilization: &["\"Device Utilization %\"="],
+    renderer: &["\"Renderer Utilization %\"="],
+    tiler: &["\"Tiler Utilization %\"="],
+    in_use_memory: &["\"In use system memory\"="],
+    alloc_memory: &["\"Alloc system memory\"="],
+    total_memory: &[],
+};
+
+const AMD_KEYS: GpuKeys = GpuKeys {
+    utilization: &["\"Device Utilization %\"=", "\"GPU Activity(%)\"="],
+    renderer: &["\"Renderer Utilization %\"="],
+    tiler: &["\"Tiler Utilization %\"="],
+    in_use_memory: &["\"vramUsedBytes\"=", "\"In use system memory\"="],
+    alloc_memory: &["\"vramAllocBytes\"=", "\"Alloc system memory\"="],
+    total_memory: &["\"vramTotalBytes\"=", "\"VRAM,totalBytes\"="],
+};
+
+const INTEL_KEYS: GpuKeys = GpuKeys {
+    utilization: &["\"Device Utilization %\"=", "\"GPU Core Utilization(%)\"="],
+    renderer: &["\"Renderer Utilization %\"="],
+    tiler: &["\"Tiler Utilization %\"="],
+    in_use_memory: &["\"inUseSystemMemory\"=", "\"In use system memory\"="],
+    alloc_memory: &["\"allocatedSystemMemory\"=", "\"Alloc system memory\"="],
+    total_memory: &[],
+};
+
+const UNKNOWN_KEYS: GpuKeys = GpuKeys {
+    utilization: &[
+        "\"Device Utilization %\"=",
+        "\"GPU Activity(%)\"=",
+        "\"GPU Core Utilization(%)\"=",
+    ],
+    renderer: &["\"Renderer Utilization %\"="],
+    tiler: &["\"Tiler Utilization %\"="],
+    in_use_memory: &[
+        "\"In use system memory\"=",
+        "\"vramUsedBytes\"=",
+        "\"inUseSystemMemory\"=",
+    ],
+    alloc_memory: &[
+        "\"Alloc system memory\"=",
+        "\"vramAllocBytes\"=",
+        "\"allocatedSystemMemory\"=",
+    ],
+    total_memory: &["\"vramTotalBytes\"=", "\"VRAM,totalBytes\"="],
+};
+
+pub struct GpuDevice {
+    pub class: String,
+    pub vendor: GpuVendor,
     pub model: String,
     pub core_count: u32,
     pub device_utilization: f64,
@@ -9,77 +112,262 @@ pub struct GpuMetrics {
     pub tiler_utilization: f64,
     pub in_use_memory: u64,
     pub alloc_memory: u64,
+    /// Dedicated VRAM, when the driver reports it.
+    pub vram_total: Option<u64>,
     pub utilization_history: History,
 }
 
-impl GpuMetrics {
-    pub fn new() -> Self {
-        let (model, core_count) = Self::detect_gpu();
+/// Where a GPU's memory comes from.
+#[derive(Clone, Copy, PartialEq)]
+pub enum GpuMemoryKind {
+    Dedicated,
+    Unified,
+}
+
+impl GpuDevice {
+    fn new(class: String) -> Self {
         Self {
-            model,
-            core_count,
+            vendor: GpuVendor::from_class(&class),
+            class,
+            model: "Unknown GPU".to_string(),
+            core_count: 0,
             device_utilization: 0.0,
             renderer_utilization: 0.0,
             tiler_utilization: 0.0,
             in_use_memory: 0,
             alloc_memory: 0,
+            vram_total: None,
             utilization_history: History::new(),
         }
     }
 
-    fn detect_gpu() -> (String, u32) {
-        let output = Command::new("ioreg")
-            .args(["-r", "-d", "1", "-c", "IOAccelerator"])
-            .output();
+    /// Memory the GPU can draw from: its own VRAM if reported, otherwise
+    /// system RAM for integrated GPUs that share it. `None` for discrete
+    /// GPUs whose VRAM size is unknown.
+    pub fn memory_total(&self, system_ram: u64) -> Option<(u64, GpuMemoryKind)> {
+        match (self.vram_total, self.vendor) {
+            (Some(vram), _) => Some((vram, GpuMemoryKind::Dedicated)),
+            (None, GpuVendor::Apple | GpuVendor::Intel) => {
+                Some((system_ram, GpuMemoryKind::Unified))
+            }
+            (None, _) => None,
+        }
+    }
 
-        let output = match output {
-            Ok(o) => String::from_utf8_lossy(&o.stdout).to_string(),
-            Err(_) => return ("Unknown GPU".to_string(), 0),
-        };
+    /// Refresh fields from this accelerator's ioreg entry.
+    fn parse(&mut self, entry: &str) {
+        let keys = self.vendor.keys();
+        self.model = extract_string(entry, "\"model\" = \"")
+            .or_else(|| extract_string(entry, "\"model\" = <\""))
+            .unwrap_or_else(|| self.class.clone());
+        self.core_count = extract_number(entry, "\"gpu-core-count\" = ").unwrap_or(0);
+        self.device_utilization = first_number(entry, keys.utilization).unwrap_or(0) as f64;
+        self.renderer_utilization = first_number(entry, keys.renderer).unwrap_or(0) as f64;
+        self.tiler_utilization = first_number(entry, keys.tiler).unwrap_or(0) as f64;
+        self.in_use_memory = first_number(entry, keys.in_use_memory).unwrap_or(0);
+        self.alloc_memory = first_number(entry, keys.alloc_memory).unwrap_or(0);
+        self.vram_total = first_number(entry, keys.total_memory).filter(|&v: &u64| v > 0);
+    }
+}
 
-        let model = Self::extract_string(&output, "\"model\" = \"")
-            .unwrap_or_else(|| "Unknown GPU".to_string());
-        let core_count = Self::extract_number(&output, "\"gpu-core-count\" = ").unwrap_or(0);
+/// A process holding a GPU client connection, with its share of GPU time
+/// since the previous sample.
+pub struct GpuProcess {
+    pub pid: u32,
+    /// Name from the client's creator string, truncated by the kernel.
+    pub name: String,
+    pub gpu_percent: f64,
+}
+
+pub struct GpuMetrics {
+    pub devices: Vec<GpuDevice>,
+    pub selected: usize,
+    /// Processes using the GPU, busiest first. Empty when the driver doesn't
+    /// report per-client GPU time.
+    pub processes: Vec<GpuProcess>,
+    prev_gpu_time: HashMap<u32, u64>,
+    last_process_sample: Option<Instant>,
+}
 
-        (model, core_count)
+impl GpuMetrics {
+    pub fn new() -> Self {
+        let mut gpu = Self {
+            devices: Vec::new(),
+            selected: 0,
+            processes: Vec::new(),
+            prev_gpu_time: HashMap::new(),
+            last_process_sample: None,
+        };
+        // Populate model names up front so the GPU tab isn't blank
+        if let Some(output) = Self::query_ioreg() {
+            gpu.parse_devices(&output);
+        }
+        gpu
     }
 
     pub fn update(&mut self) {
-        let output = Command::new("ioreg")
-            .args(["-r", "-d", "1", "-c", "IOAccelerator"])
-            .output();
+        let Some(output) = Self::query_ioreg() else {
+            return;
+        };
+        self.parse_devices(&output);
+        for device in &mut self.devices {
+            device.utilization_history.push(device.device_utilization);
+        }
+    }
 
-        let output = match output {
-            Ok(o) => String::from_utf8_lossy(&o.stdout).to_string(),
-            Err(_) => return,
+    /// Sample per-process GPU time from the accelerator's user clients.
+    /// Only Apple GPUs report `accumulatedGPUTime`, so this stays empty
+    /// elsewhere.
+    pub fn update_processes(&mut self) {
+        let Some(output) = Command::new("ioreg")
+            .args(["-r", "-c", "AGXDeviceUserClient", "-w", "0"])
+            .output()
+            .ok()
+        else {
+            return;
         };
+        let output = String::from_utf8_lossy(&output.stdout);
+
+        // Several clients can belong to one process; sum them per PID
+        let mut totals: HashMap<u32, (String, u64)> = HashMap::new();
+        for entry in output.split("+-o ").skip(1) {
+            let Some(creator) = extract_string(entry, "\"IOUserClientCreator\" = \"") else {
+                continue;
+            };
+            let Some((pid, name)) = creator
+                .strip_prefix("pid ")
+                .and_then(|rest| rest.split_once(", "))
+            else {
+                continue;
+            };
+            let Ok(pid) = pid.parse::<u32>() else {
+                continue;
+            };
+            let gpu_time: u64 = entry
+                .match_indices("\"accumulatedGPUTime\"=")
+                .filter_map(|(i, key)| extract_number::<u64>(&entry[i..], key))
+                .sum();
+            let slot = totals.entry(pid).or_insert_with(|| (name.to_string(), 0));
+            slot.1 += gpu_time;
+        }
+
+        let now = Instant::now();
+        let elapsed_ns = self
+            .last_process_sample
+            .map(|t| now.duration_since(t).as_nanos() as f64)
+            .filter(|&ns| ns > 0.0);
+        self.last_process_sample = Some(now);
+
+        self.processes = match elapsed_ns {
+            Some(elapsed_ns) => totals
+                .iter()
+                .filter_map(|(&pid, (name, time))| {
+                    let prev = *self.prev_gpu_time.get(&pid)?;
+                    let delta = time.saturating_sub(prev) as f64;
+                    Some(GpuProcess {
+                        pid,
+                        name: name.clone(),
+                        gpu_percent: (delta / elapsed_ns * 100.0).min(100.0),
+                    })
+                })
+                .filter(|p| p.gpu_percent > 0.0)
+                .collect(),
+            None => Vec::new(),
+        };
+        self.processes.sort_by(|a, b| {
+            b.gpu_percent
+                .partial_cmp(&a.gpu_percent)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        self.prev_gpu_time = totals
+            .into_iter()
+            .map(|(pid, (_, time))| (pid, time))
+            .collect();
+    }
+
+    /// The first GPU whose vendor was recognised, shown on the dashboard.
+    pub fn primary(&self) -> Option<&GpuDevice> {
+        self.devices.iter().find(|d| d.vendor != GpuVendor::Unknown)
+    }
 
-        self.device_utilization =
-            Self::extract_number(&output, "\"Device Utilization %\"=").unwrap_or(0) as f64;
-        self.renderer_utilization =
-            Self::extract_number(&output, "\"Renderer Utilization %\"=").unwrap_or(0) as f64;
-        self.tiler_utilization =
-            Self::extract_number(&output, "\"Tiler Utilization %\"=").unwrap_or(0) as f64;
-        self.in_use_memory =
-            Self::extract_number::<u64>(&output, "\"In use system memory\"=").unwrap_or(0);
-        self.alloc_memory =
-            Self::extract_number::<u64>(&output, "\"Alloc system memory\"=").unwrap_or(0);
-
-        self.utilization_history.push(self.device_utilization);
+    /// The device shown on the GPU tab.
+    pub fn device(&self) -> Option<&GpuDevice> {
+        self.devices.get(self.selected)
     }
 
-    fn extract_string(text: &str, prefix: &str) -> Option<String> {
-        let start = text.find(prefix)? + prefix.len();
-        let end = text[start..].find('"')? + start;
-        Some(text[start..end].to_string())
+    pub fn select_next(&mut self) {
+        if !self.devices.is_empty() {
+            self.selected = (self.selected + 1) % self.devices.len();
+        }
     }
 
-    fn extract_number<T: std::str::FromStr>(text: &str, prefix: &str) -> Option<T> {
-        let start = text.find(prefix)? + prefix.len();
-        let rest = &text[start..];
-        let end = rest
-            .find(|c: char| !c.is_ascii_digit())
-            .unwrap_or(rest.len());
-        rest[..end].parse().ok()
+    pub fn select_prev(&mut self) {
+        if !self.devices.is_empty() {
+            self.selected = if self.selected == 0 {
+                self.devices.len() - 1
+            } else {
+                self.selected - 1
+            };
+        }
     }
+
+    fn query_ioreg() -> Option<String> {
+        let output = Command::new("ioreg")
+            .args(["-r", "-d", "1", "-c", "IOAccelerator"])
+            .output()
+            .ok()?;
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    /// Split ioreg output into one entry per accelerator and update the
+    /// matching device, keeping history for devices seen before.
+    fn parse_devices(&mut self, output: &str) {
+        let mut entries: Vec<(String, String)> = Vec::new();
+        for line in output.lines() {
+            if line.starts_with("+-o ") {
+                let class = line
+                    .split("<class ")
+                    .nth(1)
+                    .and_then(|rest| rest.split([',', '>']).next())
+                    .unwrap_or("")
+                    .to_string();
+                entries.push((class, String::new()));
+            } else if let Some((_, body)) = entries.last_mut() {
+                body.push_str(line);
+                body.push('\n');
+            }
+        }
+
+        let mut devices = Vec::with_capacity(entries.len());
+        for (class, body) in entries {
+            let existing = self.devices.iter().position(|d| d.class == class);
+            let mut device = match existing {
+                Some(i) => self.devices.swap_remove(i),
+                None => GpuDevice::new(class),
+            };
+            device.parse(&body);
+            devices.push(device);
+        }
+        self.devices = devices;
+        self.selected = self.selected.min(self.devices.len().saturating_sub(1));
+    }
+}
+
+fn first_number<T: std::str::FromStr>(text: &str, prefixes: &[&str]) -> Option<T> {
+    prefixes.iter().find_map(|p| extract_number(text, p))
+}
+
+fn extract_string(text: &str, prefix: &str) -> Option<String> {
+    let start = text.find(prefix)? + prefix.len();
+    let end = text[start..].find('"')? + start;
+    Some(text[start..end].to_string())
+}
+
+fn extract_number<T: std::str::FromStr>(text: &str, prefix: &str) -> Option<T> {
+    let start = text.find(prefix)? + prefix.len();
+    let rest = &text[start..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
 }
@@ -2,6 +2,32 @@ use std::collections::VecDeque;
 
 const DEFAULT_CAPACITY: usize = 300; // 5 min at 1s intervals
 
+/// How samples sharing a sparkline column are combined when a history is
+/// longer than the panel is wide.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Downsample {
+    /// Smooth trends, for steady signals like CPU and memory
+    Average,
+    /// Keeps short spikes visible, for bursty signals like network and disk
+    Max,
+}
+
+impl Downsample {
+    pub fn other(self) -> Self {
+        match self {
+            Downsample::Average => Downsample::Max,
+            Downsample::Max => Downsample::Average,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Downsample::Average => "avg",
+            Downsample::Max => "max",
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct History {
     data: VecDeque<f64>,
@@ -23,6 +49,18 @@ impl History {
         self.data.push_back(value);
     }
 
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     pub fn max(&self) -> f64 {
         self.data.iter().copied().fold(0.0_f64, f64::max)
     }
@@ -32,6 +70,66 @@ impl History {
         let skip = len.saturating_sub(count);
         self.data.iter().skip(skip).map(|&v| v as u64).collect()
     }
+
+    /// The whole history squeezed into at most `count` columns, each
+    /// combining its bucket per `mode`, so a narrow panel still spans the
+    /// full window instead of only its newest samples.
+    pub fn as_u64_vec_downsampled(&self, count: usize, mode: Downsample) -> Vec<u64> {
+        let data: Vec<f64> = self.data.iter().copied().collect();
+        downsample(&data, count, mode)
+    }
+
+    /// Maximum number of samples kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Every sample as `(x, value)`, with x counting back from 0 at the
+    /// newest sample, for line charts.
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        let newest = self.data.len() as f64 - 1.0;
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (i as f64 - newest, v))
+            .collect()
+    }
+
+    /// Rolling maximum over the previous `window` samples across the whole
+    /// history, bucketed by max into at most `count` columns to line up
+    /// with `as_u64_vec_downsampled`.
+    pub fn rolling_max_downsampled(&self, window: usize, count: usize) -> Vec<u64> {
+        let maxes: Vec<f64> = (0..self.data.len())
+            .map(|i| self.max_ending_at(i, window))
+            .collect();
+        downsample(&maxes, count, Downsample::Max)
+    }
+
+    fn max_ending_at(&self, i: usize, window: usize) -> f64 {
+        let start = (i + 1).saturating_sub(window.max(1));
+        self.data.range(start..=i).copied().fold(0.0_f64, f64::max)
+    }
+}
+
+/// Split `data` into `count` even buckets combined per `mode`; shorter
+/// series are returned as is.
+fn downsample(data: &[f64], count: usize, mode: Downsample) -> Vec<u64> {
+    let len = data.len();
+    if len <= count {
+        return data.iter().map(|&v| v as u64).collect();
+    }
+    (0..count)
+        .map(|i| {
+            let start = i * len / count;
+            let end = ((i + 1) * len / count).max(start + 1);
+            let bucket = &data[start..end];
+            let value = match mode {
+                Downsample::Average => bucket.iter().sum::<f64>() / bucket.len() as f64,
+                Downsample::Max => bucket.iter().copied().fold(0.0_f64, f64::max),
+            };
+            value as u64
+        })
+        .collect()
 }
 
 impl Default for History {
@@ -1,6 +1,11 @@
 use super::history::History;
+use std::collections::VecDeque;
 use sysinfo::System;
 
+/// Swap counts as actively growing when more is in use than this many
+/// updates ago.
+const SWAP_TREND_WINDOW: usize = 5;
+
 pub struct MemoryMetrics {
     pub total_ram: u64,
     pub used_ram: u64,
@@ -13,6 +18,12 @@ pub struct MemoryMetrics {
     pub swap_percent: f64,
     pub ram_history: History,
     pub swap_history: History,
+    /// Swap use is nonzero and growing, i.e. the system is actively paging out
+    pub swapping: bool,
+    /// Swap bytes in use over the last `SWAP_TREND_WINDOW` updates. Bytes
+    /// rather than `swap_history` percentages, since macOS grows the swap
+    /// total on demand
+    recent_swap: VecDeque<u64>,
 }
 
 impl MemoryMetrics {
@@ -29,6 +40,8 @@ impl MemoryMetrics {
             swap_percent: 0.0,
             ram_history: History::new(),
             swap_history: History::new(),
+            swapping: false,
+            recent_swap: VecDeque::with_capacity(SWAP_TREND_WINDOW),
         }
     }
 
@@ -70,13 +83,26 @@ impl MemoryMetrics {
 
         self.ram_history.push(self.ram_percent);
         self.swap_history.push(self.swap_percent);
+
+        if self.recent_swap.len() >= SWAP_TREND_WINDOW {
+            self.recent_swap.pop_front();
+        }
+        self.swapping = self.used_swap > 0
+            && self
+                .recent_swap
+                .front()
+                .is_some_and(|&oldest| self.used_swap > oldest);
+        self.recent_swap.push_back(self.used_swap);
     }
 }
 
 // --- Native macOS VM statistics via host_statistics64 ---
 
+#[cfg(target_os = "macos")]
 const HOST_VM_INFO64: i32 = 4;
+#[cfg(target_os = "macos")]
 const HOST_VM_INFO64_COUNT: u32 = 38; // sizeof(vm_statistics64_data_t) / sizeof(integer_t)
+#[cfg(target_os = "macos")]
 const KERN_SUCCESS: i32 = 0;
 
 #[repr(C)]
@@ -107,11 +133,13 @@ struct VmStatistics64 {
     total_uncompressed_pages_in_compressor: u64,
 }
 
+#[cfg(target_os = "macos")]
 extern "C" {
     fn mach_host_self() -> u32;
     fn host_statistics64(host: u32, flavor: i32, info: *mut i32, count: *mut u32) -> i32;
 }
 
+#[cfg(target_os = "macos")]
 fn get_vm_statistics() -> Option<VmStatistics64> {
     unsafe {
         let mut stat: VmStatistics64 = std::mem::zeroed();
@@ -129,3 +157,9 @@ fn get_vm_statistics() -> Option<VmStatistics64> {
         }
     }
 }
+
+/// Elsewhere `update` falls back to sysinfo's used memory.
+#[cfg(not(target_os = "macos"))]
+fn get_vm_statistics() -> Option<VmStatistics64> {
+    None
+}
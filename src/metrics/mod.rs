@@ -1,4 +1,5 @@
 pub mod ai;
+pub mod ane;
 pub mod battery;
 pub mod cpu;
 pub mod disk;
@@ -7,21 +8,64 @@ pub mod history;
 pub mod memory;
 pub mod network;
 pub mod process;
+pub mod report;
 pub mod temperature;
 
 use ai::AiMetrics;
+use ane::AneMetrics;
 use battery::BatteryMetrics;
 use cpu::CpuMetrics;
 use disk::DiskMetrics;
 use gpu::GpuMetrics;
 use memory::MemoryMetrics;
 use network::NetworkMetrics;
-use process::ProcessMetrics;
-use sysinfo::{Components, Disks, Networks, ProcessesToUpdate, System};
+use process::{ProcessColumn, ProcessMetrics};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use sysinfo::{
+    Components, Disks, Networks, Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind,
+};
 use temperature::TemperatureMetrics;
 
 use crate::ui::tabs::Tab;
 
+/// A group of metrics refreshed together.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Subsystem {
+    /// CPU and memory, refreshed on every tick.
+    Core,
+    Processes,
+    Disk,
+    Network,
+    Temperatures,
+    Gpu,
+    Ai,
+    Battery,
+}
+
+impl Subsystem {
+    /// What `tab` displays. Logs come from their own stream and have none.
+    fn shown_on(tab: Tab) -> &'static [Subsystem] {
+        match tab {
+            Tab::Dashboard => &[
+                Subsystem::Core,
+                Subsystem::Processes,
+                Subsystem::Network,
+                Subsystem::Gpu,
+                Subsystem::Battery,
+            ],
+            Tab::Cpu | Tab::Memory => &[Subsystem::Core],
+            Tab::Disk => &[Subsystem::Disk],
+            Tab::Network => &[Subsystem::Network],
+            Tab::Processes => &[Subsystem::Processes],
+            Tab::Logs => &[],
+            Tab::Temperatures => &[Subsystem::Temperatures],
+            Tab::Gpu => &[Subsystem::Gpu],
+            Tab::Ai => &[Subsystem::Ai, Subsystem::Gpu],
+        }
+    }
+}
+
 pub struct MetricsCollector {
     sys: System,
     disks: Disks,
@@ -35,8 +79,19 @@ pub struct MetricsCollector {
     pub temperature: TemperatureMetrics,
     pub gpu: GpuMetrics,
     pub ai: AiMetrics,
+    pub ane: AneMetrics,
     pub battery: BatteryMetrics,
     pub boot_time: u64,
+    /// Keep sensors refreshing off the Temps tab (needed for temperature alerts).
+    pub watch_temperatures: bool,
+    /// Keep network, GPU and battery refreshing off their tabs so every
+    /// `--record` row holds live values.
+    pub recording: bool,
+    /// Minimum time between process list refreshes, which cost far more
+    /// than the CPU and memory samples taken every tick.
+    pub process_interval: Duration,
+    /// When each subsystem last refreshed
+    updated: HashMap<Subsystem, Instant>,
 }
 
 impl MetricsCollector {
@@ -64,65 +119,296 @@ impl MetricsCollector {
             temperature: TemperatureMetrics::new(),
             gpu: GpuMetrics::new(),
             ai: AiMetrics::new(),
+            ane: AneMetrics::new(),
             battery: BatteryMetrics::new(),
             boot_time,
+            watch_temperatures: false,
+            recording: false,
+            process_interval: Duration::from_millis(2000),
+            updated: HashMap::new(),
         }
     }
 
     pub fn refresh(&mut self, active_tab: Tab) {
+        self.refresh_for(Some(active_tab));
+    }
+
+    /// Refresh every subsystem regardless of the visible tab (for reports).
+    pub fn refresh_all(&mut self) {
+        self.refresh_for(None);
+    }
+
+    fn refresh_for(&mut self, active_tab: Option<Tab>) {
         // Always refresh CPU and memory (cheap)
         self.sys.refresh_cpu_usage();
         self.sys.refresh_memory();
         self.cpu.update(&self.sys);
         self.memory.update(&self.sys);
+        let now = Instant::now();
+        self.updated.insert(Subsystem::Core, now);
 
         // Only refresh expensive subsystems when their tab is visible
-        let needs_processes = matches!(active_tab, Tab::Dashboard | Tab::Processes | Tab::Ai);
-        let needs_disk = matches!(active_tab, Tab::Dashboard | Tab::Disk);
-        let needs_network = matches!(active_tab, Tab::Dashboard | Tab::Network);
-        let needs_temps = matches!(active_tab, Tab::Temperatures);
-        let needs_gpu = matches!(active_tab, Tab::Dashboard | Tab::Gpu | Tab::Ai);
-        let needs_ai = matches!(active_tab, Tab::Ai);
-        let needs_battery = matches!(active_tab, Tab::Dashboard);
+        let visible = |tabs: &[Tab]| active_tab.is_none_or(|t| tabs.contains(&t));
+        // Reports (no active tab) always get a fresh process list
+        let shows_processes = visible(&[Tab::Dashboard, Tab::Processes, Tab::Ai]);
+        let needs_processes = shows_processes && (active_tab.is_none() || self.processes_due(now));
+        let needs_disk = visible(&[Tab::Dashboard, Tab::Disk]);
+        let needs_network = visible(&[Tab::Dashboard, Tab::Network]) || self.recording;
+        let needs_temps = visible(&[Tab::Temperatures]) || self.watch_temperatures;
+        let needs_gpu = visible(&[Tab::Dashboard, Tab::Gpu, Tab::Ai]) || self.recording;
+        let needs_ai = visible(&[Tab::Ai]);
+        let needs_battery = visible(&[Tab::Dashboard]) || self.recording;
 
         if needs_processes {
-            self.sys.refresh_processes(ProcessesToUpdate::All, true);
+            // Per-process disk I/O isn't shown, so skip reading it
+            self.sys.refresh_processes_specifics(
+                ProcessesToUpdate::All,
+                true,
+                ProcessRefreshKind::nothing()
+                    .with_memory()
+                    .with_cpu()
+                    .with_user(UpdateKind::OnlyIfNotSet),
+            );
+            if self.processes.shows(ProcessColumn::Cmd) {
+                self.sys.refresh_processes_specifics(
+                    ProcessesToUpdate::All,
+                    false,
+                    ProcessRefreshKind::nothing().with_cmd(UpdateKind::OnlyIfNotSet),
+                );
+            }
+            // The default refresh skips command lines; fetch it for the inspected process
+            if let Some(ref inspected) = self.processes.inspected {
+                self.sys.refresh_processes_specifics(
+                    ProcessesToUpdate::Some(&[Pid::from_u32(inspected.pid)]),
+                    false,
+                    ProcessRefreshKind::nothing().with_cmd(UpdateKind::OnlyIfNotSet),
+                );
+            }
             self.processes.update(&self.sys);
+            self.updated.insert(Subsystem::Processes, now);
+        } else {
+            // The header's own-usage readout stays live between list updates
+            self.sys.refresh_processes_specifics(
+                ProcessesToUpdate::Some(&[self.processes.own_pid()]),
+                false,
+                ProcessRefreshKind::nothing().with_memory().with_cpu(),
+            );
+            self.processes.refresh_own(&self.sys);
+            if !shows_processes {
+                self.processes.reset_event_baseline();
+            }
         }
 
         if needs_disk {
             self.disks.refresh(true);
             self.disk.update(&self.disks);
+            self.updated.insert(Subsystem::Disk, now);
+        }
+        if visible(&[Tab::Disk]) {
+            self.disk.update_iops();
+        } else {
+            self.disk.pause_iops();
         }
 
         if needs_network {
             self.networks.refresh(true);
             self.network.update(&self.networks);
+            self.updated.insert(Subsystem::Network, now);
         }
 
         if needs_temps {
-            self.components.refresh(true);
-            self.temperature.update(&self.components);
+            self.temperature.update(&mut self.components);
+            self.updated.insert(Subsystem::Temperatures, now);
         }
 
         if needs_gpu {
             self.gpu.update();
+            self.updated.insert(Subsystem::Gpu, now);
+        }
+        if visible(&[Tab::Gpu]) {
+            self.gpu.update_processes();
         }
 
         if needs_ai {
             self.ai.update(&self.processes.processes);
+            self.ane.update();
+            self.updated.insert(Subsystem::Ai, now);
         }
 
         if needs_battery {
             self.battery.update();
+            self.updated.insert(Subsystem::Battery, now);
         }
     }
 
+    /// How long ago the oldest data shown on `tab` was refreshed. `None`
+    /// for tabs without polled metrics or before the first refresh.
+    pub fn data_age(&self, tab: Tab) -> Option<Duration> {
+        Subsystem::shown_on(tab)
+            .iter()
+            .filter_map(|s| self.updated.get(s))
+            .map(|t| t.elapsed())
+            .max()
+    }
+
+    /// Whether some data shown on `tab` missed the latest refresh, e.g. right
+    /// after switching to a tab whose subsystem was skipped while hidden.
+    pub fn is_stale(&self, tab: Tab) -> bool {
+        let Some(latest) = self.updated.get(&Subsystem::Core) else {
+            return false;
+        };
+        Subsystem::shown_on(tab)
+            .iter()
+            .any(|s| match self.updated.get(s) {
+                // A throttled process list isn't stale until its interval is up
+                Some(_) if *s == Subsystem::Processes => self.processes_due(*latest),
+                Some(t) => t < latest,
+                None => true,
+            })
+    }
+
+    /// Whether `process_interval` has passed since the process list was
+    /// last refreshed.
+    fn processes_due(&self, now: Instant) -> bool {
+        self.updated
+            .get(&Subsystem::Processes)
+            .is_none_or(|t| now.saturating_duration_since(*t) >= self.process_interval)
+    }
+
+    /// Empty every sparkline history and session counter, e.g. right before a
+    /// benchmark so older samples don't skew the scale.
+    pub fn reset_histories(&mut self) {
+        self.cpu.aggregate_history.clear();
+        self.cpu.smoothed_history.clear();
+        for history in &mut self.cpu.per_core_history {
+            history.clear();
+        }
+        self.memory.ram_history.clear();
+        self.memory.swap_history.clear();
+        self.disk.read_history.clear();
+        self.disk.write_history.clear();
+
+        let net = &mut self.network;
+        net.total_rx_history.clear();
+        net.total_tx_history.clear();
+        for iface in &mut net.interfaces {
+            iface.rx_history.clear();
+            iface.tx_history.clear();
+        }
+        net.session_rx_bytes = 0;
+        net.session_tx_bytes = 0;
+
+        self.processes.process_count_history.clear();
+        if let Some(ref mut inspected) = self.processes.inspected {
+            inspected.cpu_history.clear();
+            inspected.memory_history.clear();
+        }
+        for sensor in &mut self.temperature.sensors {
+            sensor.history.clear();
+        }
+        for gpu in &mut self.gpu.devices {
+            gpu.utilization_history.clear();
+        }
+        self.ai.cpu_history.clear();
+        self.ai.tps_history.clear();
+        self.ane.power_history.clear();
+        self.battery.percent_history.clear();
+    }
+
+    /// Full command line of `pid`, fetched on demand since the regular
+    /// refresh skips it. `None` if the process is gone or its arguments
+    /// aren't readable (other users' processes without root).
+    pub fn command_line(&mut self, pid: u32) -> Option<String> {
+        let pid = Pid::from_u32(pid);
+        self.sys.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_cmd(UpdateKind::Always),
+        );
+        let cmd: Vec<String> = self
+            .sys
+            .process(pid)?
+            .cmd()
+            .iter()
+            .map(|a| a.to_string_lossy().to_string())
+            .collect();
+        (!cmd.is_empty()).then(|| cmd.join(" "))
+    }
+
+    /// Time since boot including sleep. Uses the monotonic mach clock so
+    /// wall-clock changes don't skew it; falls back to `now - boot_time`.
     pub fn uptime(&self) -> u64 {
+        if let Some(secs) = continuous_secs() {
+            return secs;
+        }
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs();
         now.saturating_sub(self.boot_time)
     }
+
+    /// Time since boot excluding sleep, if the mach clock is available.
+    pub fn awake_uptime(&self) -> Option<u64> {
+        absolute_secs()
+    }
+}
+
+/// Turn a counter delta into a per-second rate. Dividing by the real
+/// interval keeps rates honest when refreshes are irregular: right after a
+/// tab switch, or after a subsystem sat idle while its tab was hidden.
+fn per_second(delta: u64, elapsed: Option<Duration>) -> f64 {
+    match elapsed {
+        Some(elapsed) if !elapsed.is_zero() => delta as f64 / elapsed.as_secs_f64(),
+        _ => 0.0,
+    }
+}
+
+// --- Native macOS monotonic clocks ---
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+struct MachTimebaseInfo {
+    numer: u32,
+    denom: u32,
+}
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn mach_absolute_time() -> u64;
+    fn mach_continuous_time() -> u64;
+    fn mach_timebase_info(info: *mut MachTimebaseInfo) -> i32;
+}
+
+#[cfg(target_os = "macos")]
+fn mach_ticks_to_secs(ticks: u64) -> Option<u64> {
+    let mut info = MachTimebaseInfo { numer: 0, denom: 0 };
+    let ret = unsafe { mach_timebase_info(&mut info) };
+    if ret != 0 || info.denom == 0 || ticks == 0 {
+        return None;
+    }
+    let nanos = ticks as u128 * info.numer as u128 / info.denom as u128;
+    Some((nanos / 1_000_000_000) as u64)
+}
+
+/// Seconds on the mach clock that keeps counting through sleep.
+#[cfg(target_os = "macos")]
+fn continuous_secs() -> Option<u64> {
+    mach_ticks_to_secs(unsafe { mach_continuous_time() })
+}
+
+/// Seconds on the mach clock that pauses during sleep.
+#[cfg(target_os = "macos")]
+fn absolute_secs() -> Option<u64> {
+    mach_ticks_to_secs(unsafe { mach_absolute_time() })
+}
+
+#[cfg(not(target_os = "macos"))]
+fn continuous_secs() -> Option<u64> {
+    None
+}
+
+#[cfg(not(target_os = "macos"))]
+fn absolute_secs() -> Option<u64> {
+    None
 }
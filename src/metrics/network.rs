@@ -1,16 +1,47 @@
 use super::history::History;
+use super::per_second;
+use crate::util::now_secs;
+use std::collections::HashSet;
+use std::ffi::CStr;
 use std::process::Command;
 use std::time::Instant;
 use sysinfo::Networks;
 
+/// Identity of a network interface, refreshed alongside its counters.
+#[derive(Clone, Default)]
+pub struct InterfaceDetails {
+    pub ip_addrs: Vec<String>,
+    pub mac: String,
+    pub mtu: u32,
+    pub is_up: bool,
+}
+
 pub struct InterfaceMetrics {
     pub name: String,
+    pub details: InterfaceDetails,
     pub rx_rate: f64,
     pub tx_rate: f64,
     pub rx_history: History,
     pub tx_history: History,
+    /// Packets per second, like `rx_rate`/`tx_rate`.
+    pub rx_packets: f64,
+    pub tx_packets: f64,
+    /// Cumulative error counts reported by the driver.
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    /// Whether either error count grew since the previous update.
+    pub errors_rising: bool,
     prev_rx: u64,
     prev_tx: u64,
+    prev_rx_packets: u64,
+    prev_tx_packets: u64,
+}
+
+impl InterfaceMetrics {
+    /// Whether the interface has ever sent or received anything.
+    pub fn has_traffic(&self) -> bool {
+        self.prev_rx > 0 || self.prev_tx > 0
+    }
 }
 
 pub struct ConnectionCounts {
@@ -27,6 +58,31 @@ impl ConnectionCounts {
     }
 }
 
+/// Highest total rate seen during a bandwidth test, and when.
+#[derive(Clone, Copy, Default)]
+pub struct Peak {
+    pub rate: f64,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+impl Peak {
+    fn record(&mut self, rate: f64, at: u64) {
+        if rate > self.rate {
+            *self = Peak { rate, at };
+        }
+    }
+}
+
+/// Peak RX/TX tracking while a speed test runs, started and stopped with
+/// `T` on the Network tab.
+pub struct BandwidthTest {
+    /// Seconds since the Unix epoch.
+    pub started: u64,
+    pub rx: Peak,
+    pub tx: Peak,
+}
+
 pub struct NetworkMetrics {
     pub interfaces: Vec<InterfaceMetrics>,
     pub total_rx_rate: f64,
@@ -34,7 +90,15 @@ pub struct NetworkMetrics {
     pub total_rx_history: History,
     pub total_tx_history: History,
     pub connections: ConnectionCounts,
+    /// Bytes received/sent across all interfaces since peakmon started.
+    pub session_rx_bytes: u64,
+    pub session_tx_bytes: u64,
+    pub selected_interface: usize,
+    /// Also list interfaces that have never carried traffic (idle VPNs etc.).
+    pub show_all: bool,
+    pub bandwidth_test: Option<BandwidthTest>,
     last_netstat: Option<Instant>,
+    last_update: Option<Instant>,
 }
 
 impl NetworkMetrics {
@@ -52,23 +116,62 @@ impl NetworkMetrics {
                 close_wait: 0,
                 other: 0,
             },
+            session_rx_bytes: 0,
+            session_tx_bytes: 0,
+            selected_interface: 0,
+            show_all: false,
+            bandwidth_test: None,
             last_netstat: None,
+            last_update: None,
         }
     }
 
     pub fn update(&mut self, networks: &Networks) {
+        let now = Instant::now();
+        let elapsed = self.last_update.map(|t| now.duration_since(t));
+        self.last_update = Some(now);
+
         let mut total_rx: f64 = 0.0;
         let mut total_tx: f64 = 0.0;
+        let up = up_interfaces();
 
         for (name, data) in networks.list() {
             let rx = data.total_received();
             let tx = data.total_transmitted();
+            let rx_packets = data.total_packets_received();
+            let tx_packets = data.total_packets_transmitted();
+            let rx_errors = data.total_errors_on_received();
+            let tx_errors = data.total_errors_on_transmitted();
+            let details = InterfaceDetails {
+                ip_addrs: data
+                    .ip_networks()
+                    .iter()
+                    .map(|n| format!("{}/{}", n.addr, n.prefix))
+                    .collect(),
+                mac: data.mac_address().to_string(),
+                mtu: data.mtu() as u32,
+                is_up: up.contains(name),
+            };
 
             if let Some(iface) = self.interfaces.iter_mut().find(|i| i.name == *name) {
-                iface.rx_rate = rx.saturating_sub(iface.prev_rx) as f64;
-                iface.tx_rate = tx.saturating_sub(iface.prev_tx) as f64;
+                iface.details = details;
+                let rx_delta = rx.saturating_sub(iface.prev_rx);
+                let tx_delta = tx.saturating_sub(iface.prev_tx);
+                self.session_rx_bytes += rx_delta;
+                self.session_tx_bytes += tx_delta;
+                iface.rx_rate = per_second(rx_delta, elapsed);
+                iface.tx_rate = per_second(tx_delta, elapsed);
                 iface.prev_rx = rx;
                 iface.prev_tx = tx;
+                iface.rx_packets =
+                    per_second(rx_packets.saturating_sub(iface.prev_rx_packets), elapsed);
+                iface.tx_packets =
+                    per_second(tx_packets.saturating_sub(iface.prev_tx_packets), elapsed);
+                iface.prev_rx_packets = rx_packets;
+                iface.prev_tx_packets = tx_packets;
+                iface.errors_rising = rx_errors > iface.rx_errors || tx_errors > iface.tx_errors;
+                iface.rx_errors = rx_errors;
+                iface.tx_errors = tx_errors;
                 iface.rx_history.push(iface.rx_rate);
                 iface.tx_history.push(iface.tx_rate);
                 total_rx += iface.rx_rate;
@@ -76,12 +179,20 @@ impl NetworkMetrics {
             } else {
                 let mut iface = InterfaceMetrics {
                     name: name.clone(),
+                    details,
                     rx_rate: 0.0,
                     tx_rate: 0.0,
                     rx_history: History::new(),
                     tx_history: History::new(),
+                    rx_packets: 0.0,
+                    tx_packets: 0.0,
+                    rx_errors,
+                    tx_errors,
+                    errors_rising: false,
                     prev_rx: rx,
                     prev_tx: tx,
+                    prev_rx_packets: rx_packets,
+                    prev_tx_packets: tx_packets,
                 };
                 iface.rx_history.push(0.0);
                 iface.tx_history.push(0.0);
@@ -89,16 +200,22 @@ impl NetworkMetrics {
             }
         }
 
-        // Filter out loopback and inactive interfaces for display
-        self.interfaces.retain(|i| {
-            // Keep interfaces that have seen some traffic
-            i.prev_rx > 0 || i.prev_tx > 0
-        });
+        // Drop interfaces that have disappeared; quiet ones are kept and
+        // filtered at render time instead
+        self.interfaces
+            .retain(|i| networks.list().contains_key(&i.name));
+        self.interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+        self.clamp_selection();
 
         self.total_rx_rate = total_rx;
         self.total_tx_rate = total_tx;
         self.total_rx_history.push(total_rx);
         self.total_tx_history.push(total_tx);
+        if let Some(test) = &mut self.bandwidth_test {
+            let at = now_secs();
+            test.rx.record(total_rx, at);
+            test.tx.record(total_tx, at);
+        }
 
         // Throttle netstat to every 5 seconds (subprocess spawn is expensive)
         let should_refresh = self.last_netstat.is_none_or(|t| t.elapsed().as_secs() >= 5);
@@ -108,6 +225,62 @@ impl NetworkMetrics {
         }
     }
 
+    /// Interfaces shown in the Network tab, honoring `show_all`.
+    pub fn visible_interfaces(&self) -> Vec<&InterfaceMetrics> {
+        self.interfaces
+            .iter()
+            .filter(|i| self.show_all || i.has_traffic())
+            .collect()
+    }
+
+    /// Start a bandwidth test, or stop the running one. Returns whether a
+    /// test is now running.
+    pub fn toggle_bandwidth_test(&mut self) -> bool {
+        self.bandwidth_test = match self.bandwidth_test {
+            Some(_) => None,
+            None => Some(BandwidthTest {
+                started: now_secs(),
+                rx: Peak::default(),
+                tx: Peak::default(),
+            }),
+        };
+        self.bandwidth_test.is_some()
+    }
+
+    pub fn toggle_show_all(&mut self) {
+        self.show_all = !self.show_all;
+        self.clamp_selection();
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.visible_interfaces().len();
+        if count > 0 {
+            self.selected_interface = (self.selected_interface + 1) % count;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        let count = self.visible_interfaces().len();
+        if count > 0 {
+            self.selected_interface = if self.selected_interface == 0 {
+                count - 1
+            } else {
+                self.selected_interface - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&InterfaceMetrics> {
+        self.visible_interfaces()
+            .get(self.selected_interface)
+            .copied()
+    }
+
+    fn clamp_selection(&mut self) {
+        let count = self.visible_interfaces().len();
+        self.selected_interface = self.selected_interface.min(count.saturating_sub(1));
+    }
+
     fn refresh_connections(&mut self) {
         let output = match Command::new("netstat").args(["-n", "-p", "tcp"]).output() {
             Ok(o) => String::from_utf8_lossy(&o.stdout).to_string(),
@@ -140,3 +313,24 @@ impl NetworkMetrics {
         };
     }
 }
+
+/// Names of interfaces with the IFF_UP flag set.
+fn up_interfaces() -> HashSet<String> {
+    let mut up = HashSet::new();
+    unsafe {
+        let mut addrs: *mut libc::ifaddrs = std::ptr::null_mut();
+        if libc::getifaddrs(&mut addrs) != 0 {
+            return up;
+        }
+        let mut cur = addrs;
+        while !cur.is_null() {
+            let ifa = &*cur;
+            if ifa.ifa_flags & libc::IFF_UP as u32 != 0 {
+                up.insert(CStr::from_ptr(ifa.ifa_name).to_string_lossy().into_owned());
+            }
+            cur = ifa.ifa_next;
+        }
+        libc::freeifaddrs(addrs);
+    }
+    up
+}
@@ -1,7 +1,11 @@
-use std::collections::HashMap;
-use sysinfo::{ProcessStatus, System};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
 
-use crate::util::contains_ignore_ascii_case;
+use crate::util::now_secs;
+use sysinfo::{Pid, ProcessStatus, System};
+
+use super::history::History;
+use crate::util::{contains_ignore_ascii_case, fuzzy_score};
 
 #[derive(Clone)]
 pub struct ProcessInfo {
@@ -11,7 +15,38 @@ pub struct ProcessInfo {
     pub cpu_usage: f64,
     pub memory: u64,
     pub status: ProcessState,
+    pub thread_count: u32,
+    pub priority: i32,
+    pub uid: Option<u32>,
+    /// Full command line; empty unless the Cmd column is shown.
+    pub cmd: String,
+    /// Approximate energy impact; see [`energy_impact`].
+    pub energy_impact: f64,
     pub depth: usize,
+    /// Memory of the process plus all its descendants; filled in by the tree
+    /// view, otherwise just `memory`.
+    pub subtree_memory: u64,
+}
+
+/// A jump in CPU usage of at least this many points between refreshes
+/// highlights the process for one refresh.
+const SPIKE_THRESHOLD: f64 = 20.0;
+
+/// Started/exited events kept for the Processes tab's feed.
+const MAX_PROCESS_EVENTS: usize = 50;
+
+/// PIDs below this are launched by the OS at boot.
+const SYSTEM_PID_THRESHOLD: u32 = 100;
+
+/// macOS assigns regular user accounts UIDs starting at 501; everything
+/// below (root, _windowserver, _mdnsresponder, ...) is a system account.
+const FIRST_USER_UID: u32 = 501;
+
+impl ProcessInfo {
+    /// Kernel tasks and daemons owned by root or a system account.
+    pub fn is_system(&self) -> bool {
+        self.pid < SYSTEM_PID_THRESHOLD || self.uid.is_some_and(|uid| uid < FIRST_USER_UID)
+    }
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -46,6 +81,19 @@ impl ProcessState {
             ProcessState::Unknown => "?",
         }
     }
+
+    /// Position when sorting by state, most active first: Run, Sleep,
+    /// Idle, Stop, Zombie, then unknown.
+    fn sort_rank(&self) -> u8 {
+        match self {
+            ProcessState::Run => 0,
+            ProcessState::Sleep => 1,
+            ProcessState::Idle => 2,
+            ProcessState::Stop => 3,
+            ProcessState::Zombie => 4,
+            ProcessState::Unknown => 5,
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -54,6 +102,112 @@ pub enum ProcessSortField {
     Name,
     Cpu,
     Memory,
+    Energy,
+    State,
+}
+
+/// A column of the process table, chosen with `--columns`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ProcessColumn {
+    Pid,
+    Name,
+    User,
+    State,
+    Cpu,
+    Energy,
+    Memory,
+    Threads,
+    Nice,
+    Cmd,
+    /// Memory rolled up over a process's subtree. Added automatically in
+    /// tree mode rather than chosen with `--columns`.
+    TreeMemory,
+}
+
+impl ProcessColumn {
+    pub const ALL: [ProcessColumn; 10] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Name,
+        ProcessColumn::User,
+        ProcessColumn::State,
+        ProcessColumn::Cpu,
+        ProcessColumn::Energy,
+        ProcessColumn::Memory,
+        ProcessColumn::Threads,
+        ProcessColumn::Nice,
+        ProcessColumn::Cmd,
+    ];
+
+    pub const DEFAULT: [ProcessColumn; 8] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Name,
+        ProcessColumn::State,
+        ProcessColumn::Cpu,
+        ProcessColumn::Energy,
+        ProcessColumn::Memory,
+        ProcessColumn::Threads,
+        ProcessColumn::Nice,
+    ];
+
+    /// Name used by `--columns`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "pid",
+            ProcessColumn::Name => "name",
+            ProcessColumn::User => "user",
+            ProcessColumn::State => "state",
+            ProcessColumn::Cpu => "cpu",
+            ProcessColumn::Energy => "energy",
+            ProcessColumn::Memory => "mem",
+            ProcessColumn::Threads => "threads",
+            ProcessColumn::Nice => "nice",
+            ProcessColumn::Cmd => "cmd",
+            ProcessColumn::TreeMemory => "tree-mem",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.key().eq_ignore_ascii_case(key.trim()))
+    }
+
+    /// The sort field whose header arrow this column shows, if any.
+    pub fn sort_field(&self) -> Option<ProcessSortField> {
+        match self {
+            ProcessColumn::Pid => Some(ProcessSortField::Pid),
+            ProcessColumn::Name => Some(ProcessSortField::Name),
+            ProcessColumn::Cpu => Some(ProcessSortField::Cpu),
+            ProcessColumn::Energy => Some(ProcessSortField::Energy),
+            ProcessColumn::Memory => Some(ProcessSortField::Memory),
+            ProcessColumn::State => Some(ProcessSortField::State),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ProcessEventKind {
+    Started,
+    Exited,
+}
+
+/// A process that appeared or disappeared between two refreshes.
+pub struct ProcessEvent {
+    /// Seconds since the Unix epoch of the refresh that noticed it.
+    pub at: u64,
+    pub pid: u32,
+    pub name: String,
+    pub kind: ProcessEventKind,
+}
+
+/// Extra per-process detail tracked only for the process open in the inspector.
+pub struct InspectedProcess {
+    pub pid: u32,
+    pub cmd: String,
+    pub alive: bool,
+    pub cpu_history: History,
+    pub memory_history: History,
 }
 
 pub struct ProcessMetrics {
@@ -61,7 +215,41 @@ pub struct ProcessMetrics {
     pub sort_field: ProcessSortField,
     pub sort_ascending: bool,
     pub filter: String,
+    /// Match the filter as a subsequence and rank by closeness instead of
+    /// requiring a substring
+    pub fuzzy: bool,
+    pub search_query: String,
     pub tree_mode: bool,
+    pub hide_system: bool,
+    /// Show only processes owned by the user running peakmon
+    pub only_mine: bool,
+    my_uid: u32,
+    /// peakmon's own PID, so it can report what it costs to run
+    own_pid: u32,
+    /// peakmon's own CPU % and memory, kept current every tick even while
+    /// the full list is throttled or hidden
+    pub own_usage: Option<(f64, u64)>,
+    pub running_count: usize,
+    pub zombie_count: usize,
+    pub total_memory: u64,
+    pub process_count_history: History,
+    pub inspected: Option<InspectedProcess>,
+    pub columns: Vec<ProcessColumn>,
+    /// Account names by UID, filled while the User column is shown
+    usernames: HashMap<u32, String>,
+    /// PIDs whose CPU usage just jumped by [`SPIKE_THRESHOLD`] or more
+    pub spiked: HashSet<u32>,
+    /// CPU usage per PID at the previous update
+    prev_cpu: HashMap<u32, f64>,
+    /// Processes that started or exited, oldest first, at most
+    /// [`MAX_PROCESS_EVENTS`]. Catches short-lived processes (build steps,
+    /// cron jobs) that would otherwise flash by between refreshes.
+    pub events: VecDeque<ProcessEvent>,
+    /// Name per PID at the previous update
+    prev_names: HashMap<u32, String>,
+    /// Cumulative wakeups per PID at the previous update
+    prev_wakeups: HashMap<u32, u64>,
+    last_update: Option<Instant>,
 }
 
 impl ProcessMetrics {
@@ -71,11 +259,102 @@ impl ProcessMetrics {
             sort_field: ProcessSortField::Cpu,
             sort_ascending: false,
             filter: String::new(),
+            fuzzy: false,
+            search_query: String::new(),
             tree_mode: false,
+            hide_system: false,
+            only_mine: false,
+            my_uid: unsafe { libc::getuid() },
+            own_pid: std::process::id(),
+            own_usage: None,
+            running_count: 0,
+            zombie_count: 0,
+            total_memory: 0,
+            process_count_history: History::new(),
+            inspected: None,
+            columns: ProcessColumn::DEFAULT.to_vec(),
+            usernames: HashMap::new(),
+            spiked: HashSet::new(),
+            prev_cpu: HashMap::new(),
+            events: VecDeque::with_capacity(MAX_PROCESS_EVENTS),
+            prev_names: HashMap::new(),
+            prev_wakeups: HashMap::new(),
+            last_update: None,
+        }
+    }
+
+    /// Compare the new process list with the previous one and log what
+    /// started or exited. A reused PID counts as an exit and a start.
+    fn record_events(&mut self) {
+        let names: HashMap<u32, String> = self
+            .processes
+            .iter()
+            .map(|p| (p.pid, p.name.clone()))
+            .collect();
+        // Everything is new on the first update (or after a gap), which isn't news
+        if !self.prev_names.is_empty() {
+            let at = now_secs();
+            let changed = |from: &HashMap<u32, String>, to: &HashMap<u32, String>| {
+                let mut gone: Vec<(u32, String)> = from
+                    .iter()
+                    .filter(|(pid, name)| to.get(pid) != Some(name))
+                    .map(|(&pid, name)| (pid, name.clone()))
+                    .collect();
+                gone.sort_unstable_by_key(|(pid, _)| *pid);
+                gone
+            };
+            let exited = changed(&self.prev_names, &names)
+                .into_iter()
+                .map(|(pid, name)| (pid, name, ProcessEventKind::Exited));
+            let started = changed(&names, &self.prev_names)
+                .into_iter()
+                .map(|(pid, name)| (pid, name, ProcessEventKind::Started));
+            for (pid, name, kind) in exited.chain(started) {
+                if self.events.len() >= MAX_PROCESS_EVENTS {
+                    self.events.pop_front();
+                }
+                self.events.push_back(ProcessEvent {
+                    at,
+                    pid,
+                    name,
+                    kind,
+                });
+            }
         }
+        self.prev_names = names;
+    }
+
+    /// Drop the list `record_events` diffs against, so the next update starts
+    /// a fresh baseline. Called while the list isn't refreshed; diffing across
+    /// the gap would stamp everything that changed meanwhile with the time of
+    /// the catch-up refresh.
+    pub fn reset_event_baseline(&mut self) {
+        self.prev_names.clear();
+    }
+
+    /// peakmon's own PID, for refreshing just that process between full
+    /// list updates.
+    pub fn own_pid(&self) -> Pid {
+        Pid::from_u32(self.own_pid)
+    }
+
+    /// Take peakmon's own usage from `sys`, which must have refreshed
+    /// [`Self::own_pid`].
+    pub fn refresh_own(&mut self, sys: &System) {
+        self.own_usage = sys
+            .process(self.own_pid())
+            .map(|p| (p.cpu_usage() as f64, p.memory()));
     }
 
     pub fn update(&mut self, sys: &System) {
+        let now = Instant::now();
+        let elapsed = self
+            .last_update
+            .map(|t| now.duration_since(t).as_secs_f64())
+            .filter(|&secs| secs > 0.0);
+        self.last_update = Some(now);
+
+        let mut wakeups = HashMap::with_capacity(self.prev_wakeups.len());
         self.processes = sys
             .processes()
             .iter()
@@ -85,17 +364,104 @@ impl ProcessMetrics {
                     let v: usize = p.into();
                     v as u32
                 });
+                let cpu_usage = proc_info.cpu_usage() as f64;
+
+                // Wakeup rate needs two samples; until then energy is CPU only
+                let total_wakeups = get_wakeups(pid_val as i32);
+                let wakeups_per_sec = match (total_wakeups, elapsed) {
+                    (Some(total), Some(secs)) => self
+                        .prev_wakeups
+                        .get(&(pid_val as u32))
+                        .map_or(0.0, |&prev| total.saturating_sub(prev) as f64 / secs),
+                    _ => 0.0,
+                };
+                if let Some(total) = total_wakeups {
+                    wakeups.insert(pid_val as u32, total);
+                }
+
                 ProcessInfo {
                     pid: pid_val as u32,
                     parent_pid: ppid,
                     name: proc_info.name().to_string_lossy().to_string(),
-                    cpu_usage: proc_info.cpu_usage() as f64,
+                    cpu_usage,
                     memory: proc_info.memory(),
+                    subtree_memory: proc_info.memory(),
                     status: ProcessState::from_sysinfo(proc_info.status()),
+                    thread_count: get_thread_count(pid_val as i32).unwrap_or(0),
+                    priority: get_priority(pid_val as u32),
+                    uid: proc_info.user_id().map(|uid| **uid),
+                    cmd: proc_info
+                        .cmd()
+                        .iter()
+                        .map(|a| a.to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    energy_impact: energy_impact(cpu_usage, wakeups_per_sec),
                     depth: 0,
                 }
             })
             .collect();
+        self.prev_wakeups = wakeups;
+
+        // Processes seen for the first time aren't spikes, just new
+        self.spiked = self
+            .processes
+            .iter()
+            .filter(|p| {
+                self.prev_cpu
+                    .get(&p.pid)
+                    .is_some_and(|&prev| p.cpu_usage - prev >= SPIKE_THRESHOLD)
+            })
+            .map(|p| p.pid)
+            .collect();
+        self.prev_cpu = self
+            .processes
+            .iter()
+            .map(|p| (p.pid, p.cpu_usage))
+            .collect();
+        self.record_events();
+        self.refresh_own(sys);
+
+        if self.shows(ProcessColumn::User) {
+            for uid in self.processes.iter().filter_map(|p| p.uid) {
+                self.usernames
+                    .entry(uid)
+                    .or_insert_with(|| get_username(uid).unwrap_or_else(|| uid.to_string()));
+            }
+        }
+
+        // Aggregate totals across all processes (independent of filter)
+        self.running_count = self
+            .processes
+            .iter()
+            .filter(|p| p.status == ProcessState::Run)
+            .count();
+        self.zombie_count = self
+            .processes
+            .iter()
+            .filter(|p| p.status == ProcessState::Zombie)
+            .count();
+        self.total_memory = self.processes.iter().map(|p| p.memory).sum();
+        self.process_count_history.push(self.processes.len() as f64);
+
+        if let Some(ref mut inspected) = self.inspected {
+            match sys.process(Pid::from_u32(inspected.pid)) {
+                Some(proc_info) => {
+                    inspected.alive = true;
+                    inspected.cpu_history.push(proc_info.cpu_usage() as f64);
+                    inspected.memory_history.push(proc_info.memory() as f64);
+                    let cmd: Vec<String> = proc_info
+                        .cmd()
+                        .iter()
+                        .map(|a| a.to_string_lossy().to_string())
+                        .collect();
+                    if !cmd.is_empty() {
+                        inspected.cmd = cmd.join(" ");
+                    }
+                }
+                None => inspected.alive = false,
+            }
+        }
 
         self.sort();
     }
@@ -150,6 +516,35 @@ impl ProcessMetrics {
                     }
                 });
             }
+            ProcessSortField::Energy => {
+                self.processes.sort_unstable_by(|a, b| {
+                    let cmp = a
+                        .energy_impact
+                        .partial_cmp(&b.energy_impact)
+                        .unwrap_or(std::cmp::Ordering::Equal);
+                    if ascending {
+                        cmp
+                    } else {
+                        cmp.reverse()
+                    }
+                });
+            }
+            ProcessSortField::State => {
+                // Descending (the default) lists running processes first.
+                // PID breaks ties so rows don't shuffle between refreshes.
+                self.processes.sort_unstable_by(|a, b| {
+                    let cmp = b
+                        .status
+                        .sort_rank()
+                        .cmp(&a.status.sort_rank())
+                        .then(a.pid.cmp(&b.pid));
+                    if ascending {
+                        cmp.reverse()
+                    } else {
+                        cmp
+                    }
+                });
+            }
         }
     }
 
@@ -163,36 +558,78 @@ impl ProcessMetrics {
         self.sort();
     }
 
+    /// Columns the table shows: the chosen ones, plus the subtree memory
+    /// rollup (next to Memory) in tree mode.
+    pub fn table_columns(&self) -> Vec<ProcessColumn> {
+        let mut columns = self.columns.clone();
+        if self.tree_mode {
+            let at = columns
+                .iter()
+                .position(|&c| c == ProcessColumn::Memory)
+                .map_or(columns.len(), |i| i + 1);
+            columns.insert(at, ProcessColumn::TreeMemory);
+        }
+        columns
+    }
+
+    /// Whether `p` survives the system and my-user toggles.
+    fn is_shown(&self, p: &ProcessInfo) -> bool {
+        !(self.hide_system && p.is_system()) && (!self.only_mine || p.uid == Some(self.my_uid))
+    }
+
+    fn matches_filter(&self, name: &str) -> bool {
+        if self.fuzzy {
+            fuzzy_score(&self.filter, name).is_some()
+        } else {
+            contains_ignore_ascii_case(name, &self.filter)
+        }
+    }
+
     pub fn filtered_processes(&self) -> Vec<&ProcessInfo> {
-        if self.filter.is_empty() {
-            self.processes.iter().collect()
+        let mut filtered: Vec<&ProcessInfo> = self
+            .processes
+            .iter()
+            .filter(|p| self.is_shown(p))
+            .filter(|p| self.filter.is_empty() || self.matches_filter(&p.name))
+            .collect();
+        // Best fuzzy matches first; the stable sort keeps the sort field's
+        // order among equal scores
+        if self.fuzzy && !self.filter.is_empty() {
+            filtered.sort_by_cached_key(|p| {
+                std::cmp::Reverse(fuzzy_score(&self.filter, &p.name).unwrap_or(0))
+            });
+        }
+        filtered
+    }
+
+    /// The rows the Processes tab shows, in display order: the tree view in
+    /// tree mode, otherwise the filtered flat list.
+    pub fn displayed_processes(&self) -> Vec<ProcessInfo> {
+        if self.tree_mode {
+            self.tree_view()
         } else {
-            self.processes
-                .iter()
-                .filter(|p| contains_ignore_ascii_case(&p.name, &self.filter))
-                .collect()
+            self.filtered_processes().into_iter().cloned().collect()
         }
     }
 
-    pub fn filtered_count(&self) -> usize {
-        if self.filter.is_empty() {
-            self.processes.len()
+    pub fn displayed_count(&self) -> usize {
+        if self.tree_mode {
+            self.tree_view().len()
         } else {
-            self.processes
-                .iter()
-                .filter(|p| contains_ignore_ascii_case(&p.name, &self.filter))
-                .count()
+            self.filtered_processes().len()
         }
     }
 
     pub fn tree_view(&self) -> Vec<ProcessInfo> {
-        let pid_map: HashMap<u32, &ProcessInfo> =
-            self.processes.iter().map(|p| (p.pid, p)).collect();
+        // Hidden processes drop out of the tree; their children become
+        // roots
+        let shown: Vec<&ProcessInfo> = self.processes.iter().filter(|p| self.is_shown(p)).collect();
+        let pid_map: HashMap<u32, &ProcessInfo> = shown.iter().map(|p| (p.pid, *p)).collect();
 
         let mut children_map: HashMap<u32, Vec<u32>> = HashMap::new();
         let mut roots = Vec::new();
 
-        for proc in &self.processes {
+        for proc in shown {
             match proc.parent_pid {
                 Some(ppid) if pid_map.contains_key(&ppid) && ppid != proc.pid => {
                     children_map.entry(ppid).or_default().push(proc.pid);
@@ -219,33 +656,342 @@ impl ProcessMetrics {
 
         // Apply filter
         if !self.filter.is_empty() {
-            result.retain(|p| contains_ignore_ascii_case(&p.name, &self.filter));
+            result.retain(|p| self.matches_filter(&p.name));
         }
 
         result
     }
 
+    /// Append `pid` and its descendants depth-first, returning the memory of
+    /// the whole subtree.
     fn build_tree(
         pid: u32,
         depth: usize,
         pid_map: &HashMap<u32, &ProcessInfo>,
         children_map: &HashMap<u32, Vec<u32>>,
         result: &mut Vec<ProcessInfo>,
-    ) {
-        if let Some(proc) = pid_map.get(&pid) {
-            let mut p = (*proc).clone();
-            p.depth = depth;
-            result.push(p);
-
-            if let Some(children) = children_map.get(&pid) {
-                for child_pid in children {
-                    Self::build_tree(*child_pid, depth + 1, pid_map, children_map, result);
-                }
+    ) -> u64 {
+        let Some(proc) = pid_map.get(&pid) else {
+            return 0;
+        };
+        let index = result.len();
+        let mut p = (*proc).clone();
+        p.depth = depth;
+        result.push(p);
+
+        // Children are summed first, so the parent's total is set last
+        let mut total = proc.memory;
+        if let Some(children) = children_map.get(&pid) {
+            for child_pid in children {
+                total += Self::build_tree(*child_pid, depth + 1, pid_map, children_map, result);
             }
         }
+        result[index].subtree_memory = total;
+        total
+    }
+
+    /// The process shown at `index` in the current view (tree or flat, filter applied).
+    pub fn process_at(&self, index: usize) -> Option<ProcessInfo> {
+        self.displayed_processes().into_iter().nth(index)
+    }
+
+    /// Display index of `pid` in the current view (tree or filtered list).
+    pub fn position_of(&self, pid: u32) -> Option<usize> {
+        self.displayed_processes().iter().position(|p| p.pid == pid)
+    }
+
+    /// Index of the next (or previous) displayed process whose name matches
+    /// `search_query`, starting after `from` and wrapping around the list.
+    pub fn find_match(&self, from: usize, forward: bool) -> Option<usize> {
+        if self.search_query.is_empty() {
+            return None;
+        }
+
+        let items = self.displayed_processes();
+        let len = items.len();
+        if len == 0 {
+            return None;
+        }
+        let from = from.min(len - 1);
+
+        (1..=len)
+            .map(|step| {
+                if forward {
+                    (from + step) % len
+                } else {
+                    (from + len - step) % len
+                }
+            })
+            .find(|&i| contains_ignore_ascii_case(&items[i].name, &self.search_query))
+    }
+
+    pub fn shows(&self, column: ProcessColumn) -> bool {
+        self.columns.contains(&column)
+    }
+
+    /// Account name owning `uid`, or the UID itself if it has no entry.
+    pub fn username(&self, uid: Option<u32>) -> &str {
+        uid.and_then(|uid| self.usernames.get(&uid))
+            .map_or("?", String::as_str)
+    }
+
+    pub fn inspect(&mut self, pid: u32) {
+        let mut inspected = InspectedProcess {
+            pid,
+            cmd: String::new(),
+            alive: true,
+            cpu_history: History::new(),
+            memory_history: History::new(),
+        };
+        // Seed with the current sample so the popup isn't empty until next refresh
+        if let Some(p) = self.processes.iter().find(|p| p.pid == pid) {
+            inspected.cpu_history.push(p.cpu_usage);
+            inspected.memory_history.push(p.memory as f64);
+        }
+        self.inspected = Some(inspected);
+    }
+
+    pub fn stop_inspect(&mut self) {
+        self.inspected = None;
+    }
+
+    pub fn toggle_fuzzy(&mut self) {
+        self.fuzzy = !self.fuzzy;
     }
 
     pub fn toggle_tree_mode(&mut self) {
         self.tree_mode = !self.tree_mode;
     }
+
+    pub fn toggle_hide_system(&mut self) {
+        self.hide_system = !self.hide_system;
+    }
+
+    pub fn toggle_only_mine(&mut self) {
+        self.only_mine = !self.only_mine;
+    }
+}
+
+// --- Native macOS per-process task info via proc_pidinfo ---
+
+#[cfg(target_os = "macos")]
+const PROC_PIDTASKINFO: i32 = 4;
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+struct ProcTaskInfo {
+    pti_virtual_size: u64,
+    pti_resident_size: u64,
+    pti_total_user: u64,
+    pti_total_system: u64,
+    pti_threads_user: u64,
+    pti_threads_system: u64,
+    pti_policy: i32,
+    pti_faults: i32,
+    pti_pageins: i32,
+    pti_cow_faults: i32,
+    pti_messages_sent: i32,
+    pti_messages_received: i32,
+    pti_syscalls_mach: i32,
+    pti_syscalls_unix: i32,
+    pti_csw: i32,
+    pti_threadnum: i32,
+    pti_numrunning: i32,
+    pti_priority: i32,
+}
+
+#[cfg(target_os = "macos")]
+const RUSAGE_INFO_V2: i32 = 2;
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+struct RusageInfoV2 {
+    ri_uuid: [u8; 16],
+    ri_user_time: u64,
+    ri_system_time: u64,
+    ri_pkg_idle_wkups: u64,
+    ri_interrupt_wkups: u64,
+    ri_pageins: u64,
+    ri_wired_size: u64,
+    ri_resident_size: u64,
+    ri_phys_footprint: u64,
+    ri_proc_start_abstime: u64,
+    ri_proc_exit_abstime: u64,
+    ri_child_user_time: u64,
+    ri_child_system_time: u64,
+    ri_child_pkg_idle_wkups: u64,
+    ri_child_interrupt_wkups: u64,
+    ri_child_pageins: u64,
+    ri_child_elapsed_abstime: u64,
+    ri_diskio_bytesread: u64,
+    ri_diskio_byteswritten: u64,
+}
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn proc_pidinfo(pid: i32, flavor: i32, arg: u64, buffer: *mut libc::c_void, size: i32) -> i32;
+    fn proc_pid_rusage(pid: i32, flavor: i32, buffer: *mut RusageInfoV2) -> i32;
+}
+
+/// Energy cost of one wakeup per second, in CPU-percent. Waking an idle
+/// package costs roughly 200µs of CPU time, i.e. 0.02% of one core per
+/// wakeup/s.
+const WAKEUP_WEIGHT: f64 = 0.02;
+
+/// Rough stand-in for Activity Monitor's "Energy Impact":
+///
+/// `energy = cpu% + WAKEUP_WEIGHT × wakeups/s`
+///
+/// Activity Monitor also weighs GPU time, disk and network I/O; those
+/// aren't attributed per process here yet.
+fn energy_impact(cpu_usage: f64, wakeups_per_sec: f64) -> f64 {
+    cpu_usage + WAKEUP_WEIGHT * wakeups_per_sec
+}
+
+/// Cumulative idle + interrupt wakeups for a process, or `None` if its
+/// resource usage isn't readable.
+#[cfg(target_os = "macos")]
+fn get_wakeups(pid: i32) -> Option<u64> {
+    unsafe {
+        let mut info: RusageInfoV2 = std::mem::zeroed();
+        if proc_pid_rusage(pid, RUSAGE_INFO_V2, &mut info) == 0 {
+            Some(info.ri_pkg_idle_wkups + info.ri_interrupt_wkups)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn get_wakeups(_pid: i32) -> Option<u64> {
+    None
+}
+
+/// Nice value of a process. A -1 from a vanished process is indistinguishable
+/// from a real nice of -1, which is harmless for display.
+fn get_priority(pid: u32) -> i32 {
+    unsafe { libc::getpriority(libc::PRIO_PROCESS, pid as libc::id_t) }
+}
+
+/// Login name for `uid` from the password database.
+fn get_username(uid: u32) -> Option<String> {
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0 as libc::c_char; 1024];
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    let ret = unsafe {
+        libc::getpwuid_r(
+            uid as libc::uid_t,
+            &mut pwd,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &mut result,
+        )
+    };
+    if ret != 0 || result.is_null() {
+        return None;
+    }
+    let name = unsafe { std::ffi::CStr::from_ptr(pwd.pw_name) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+/// Signals offered by the Processes tab's signal menu.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Signal {
+    Term,
+    Kill,
+    Int,
+    Hup,
+    Stop,
+    Cont,
+}
+
+impl Signal {
+    pub const ALL: [Signal; 6] = [
+        Signal::Term,
+        Signal::Kill,
+        Signal::Int,
+        Signal::Hup,
+        Signal::Stop,
+        Signal::Cont,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Signal::Term => "SIGTERM",
+            Signal::Kill => "SIGKILL",
+            Signal::Int => "SIGINT",
+            Signal::Hup => "SIGHUP",
+            Signal::Stop => "SIGSTOP",
+            Signal::Cont => "SIGCONT",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Signal::Term => "ask to terminate",
+            Signal::Kill => "kill immediately (can't be caught)",
+            Signal::Int => "interrupt, like Ctrl+C",
+            Signal::Hup => "hang up; many daemons reload config",
+            Signal::Stop => "pause until SIGCONT",
+            Signal::Cont => "resume a stopped process",
+        }
+    }
+
+    fn number(&self) -> libc::c_int {
+        match self {
+            Signal::Term => libc::SIGTERM,
+            Signal::Kill => libc::SIGKILL,
+            Signal::Int => libc::SIGINT,
+            Signal::Hup => libc::SIGHUP,
+            Signal::Stop => libc::SIGSTOP,
+            Signal::Cont => libc::SIGCONT,
+        }
+    }
+}
+
+/// Send `signal` to a process.
+pub fn send_signal(pid: u32, signal: Signal) -> std::io::Result<()> {
+    let ret = unsafe { libc::kill(pid as libc::pid_t, signal.number()) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Set the nice value of a process.
+pub fn set_priority(pid: u32, nice: i32) -> std::io::Result<()> {
+    let ret = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, nice) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Thread count for a process, or `None` if the task info isn't readable
+/// (e.g. processes owned by other users without elevated privileges).
+#[cfg(target_os = "macos")]
+fn get_thread_count(pid: i32) -> Option<u32> {
+    unsafe {
+        let mut info: ProcTaskInfo = std::mem::zeroed();
+        let size = std::mem::size_of::<ProcTaskInfo>() as i32;
+        let ret = proc_pidinfo(
+            pid,
+            PROC_PIDTASKINFO,
+            0,
+            &mut info as *mut ProcTaskInfo as *mut libc::c_void,
+            size,
+        );
+        if ret == size {
+            Some(info.pti_threadnum.max(0) as u32)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn get_thread_count(_pid: i32) -> Option<u32> {
+    None
 }
@@ -0,0 +1,235 @@
+use std::fmt::Write;
+
+use super::temperature::TempUnit;
+use super::MetricsCollector;
+use crate::util::{
+    format_bytes, format_percent, format_rate, format_uptime, local_datetime, now_secs,
+    write_string,
+};
+
+/// Processes listed in the report's top-processes table.
+const TOP_PROCESSES: usize = 10;
+
+impl MetricsCollector {
+    /// A full-system snapshot as Markdown, for bug reports and tickets.
+    pub fn to_markdown(&self, hostname: &str, temp_unit: TempUnit) -> String {
+        write_string(|md| self.write_markdown(md, hostname, temp_unit))
+    }
+
+    /// A one-line summary for pasting into chats and tickets.
+    pub fn summary_line(&self, hostname: &str) -> String {
+        let [l1, l5, l15] = self.cpu.load_avg;
+        let mem = &self.memory;
+        let mut line = format!(
+            "{hostname}: up {}, CPU {}, mem {} / {} ({}), load {l1:.2} {l5:.2} {l15:.2}",
+            format_uptime(self.uptime()),
+            format_percent(self.cpu.aggregate_usage),
+            format_bytes(mem.used_ram),
+            format_bytes(mem.total_ram),
+            format_percent(mem.ram_percent)
+        );
+        let top = self.processes.processes.iter().max_by(|a, b| {
+            a.cpu_usage
+                .partial_cmp(&b.cpu_usage)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        if let Some(p) = top {
+            let _ = write!(
+                line,
+                ", top {} (PID {}, {:.1}% CPU)",
+                p.name, p.pid, p.cpu_usage
+            );
+        }
+        line
+    }
+
+    fn write_markdown(
+        &self,
+        md: &mut String,
+        hostname: &str,
+        temp_unit: TempUnit,
+    ) -> std::fmt::Result {
+        writeln!(md, "# peakmon report: {hostname}")?;
+        writeln!(md)?;
+        writeln!(md, "- Generated: {}", local_datetime(now_secs()))?;
+        writeln!(md, "- Uptime: {}", format_uptime(self.uptime()))?;
+        let [l1, l5, l15] = self.cpu.load_avg;
+        writeln!(md, "- Load average: {l1:.2} {l5:.2} {l15:.2}")?;
+
+        let cpu = &self.cpu;
+        writeln!(md, "\n## CPU\n")?;
+        writeln!(md, "| Usage | User | System | Idle | Cores |")?;
+        writeln!(md, "|---|---|---|---|---|")?;
+        writeln!(
+            md,
+            "| {} | {} | {} | {} | {} |",
+            format_percent(cpu.aggregate_usage),
+            format_percent(cpu.breakdown.user),
+            format_percent(cpu.breakdown.system),
+            format_percent(cpu.breakdown.idle),
+            cpu.core_count
+        )?;
+
+        let mem = &self.memory;
+        writeln!(md, "\n## Memory\n")?;
+        writeln!(md, "| | Used | Total | % |")?;
+        writeln!(md, "|---|---|---|---|")?;
+        writeln!(
+            md,
+            "| RAM | {} | {} | {} |",
+            format_bytes(mem.used_ram),
+            format_bytes(mem.total_ram),
+            format_percent(mem.ram_percent)
+        )?;
+        writeln!(
+            md,
+            "| Swap | {} | {} | {} |",
+            format_bytes(mem.used_swap),
+            format_bytes(mem.total_swap),
+            format_percent(mem.swap_percent)
+        )?;
+
+        writeln!(md, "\n## Disks\n")?;
+        writeln!(md, "| Name | Mount | Total | Available | Used |")?;
+        writeln!(md, "|---|---|---|---|---|")?;
+        for d in &self.disk.disks {
+            writeln!(
+                md,
+                "| {} | {} | {} | {} | {} |",
+                d.name,
+                d.mount_point,
+                format_bytes(d.total_space),
+                format_bytes(d.available_space),
+                format_percent(d.used_percent)
+            )?;
+        }
+
+        let net = &self.network;
+        writeln!(md, "\n## Network\n")?;
+        writeln!(
+            md,
+            "Total RX {} / TX {}, {} TCP connections ({} established)\n",
+            format_rate(net.total_rx_rate),
+            format_rate(net.total_tx_rate),
+            net.connections.total(),
+            net.connections.established
+        )?;
+        writeln!(md, "| Interface | Status | IP | RX | TX |")?;
+        writeln!(md, "|---|---|---|---|---|")?;
+        for iface in net.interfaces.iter().filter(|i| i.has_traffic()) {
+            writeln!(
+                md,
+                "| {} | {} | {} | {} | {} |",
+                iface.name,
+                if iface.details.is_up { "up" } else { "down" },
+                iface.details.ip_addrs.join(", "),
+                format_rate(iface.rx_rate),
+                format_rate(iface.tx_rate)
+            )?;
+        }
+
+        if !self.gpu.devices.is_empty() {
+            writeln!(md, "\n## GPU\n")?;
+            writeln!(
+                md,
+                "| Model | Vendor | Cores | Utilization | Memory in use |"
+            )?;
+            writeln!(md, "|---|---|---|---|---|")?;
+            for gpu in &self.gpu.devices {
+                writeln!(
+                    md,
+                    "| {} | {} | {} | {:.0}% | {} |",
+                    gpu.model,
+                    gpu.vendor.label(),
+                    gpu.core_count,
+                    gpu.device_utilization,
+                    format_bytes(gpu.in_use_memory)
+                )?;
+            }
+        }
+
+        writeln!(md, "\n## Top processes (by CPU)\n")?;
+        writeln!(md, "| PID | Name | CPU | Memory |")?;
+        writeln!(md, "|---|---|---|---|")?;
+        let mut top: Vec<_> = self.processes.processes.iter().collect();
+        top.sort_by(|a, b| {
+            b.cpu_usage
+                .partial_cmp(&a.cpu_usage)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for p in top.into_iter().take(TOP_PROCESSES) {
+            writeln!(
+                md,
+                "| {} | {} | {:.1}% | {} |",
+                p.pid,
+                p.name,
+                p.cpu_usage,
+                format_bytes(p.memory)
+            )?;
+        }
+
+        writeln!(md, "\n## AI services\n")?;
+        let detected: Vec<_> = self.ai.services.iter().filter(|s| s.detected).collect();
+        if detected.is_empty() {
+            writeln!(md, "None detected.")?;
+        }
+        for service in detected {
+            let version = service
+                .version
+                .as_deref()
+                .map(|v| format!(" {v}"))
+                .unwrap_or_default();
+            let pid = service
+                .pid
+                .map(|p| format!(" (PID {p})"))
+                .unwrap_or_default();
+            writeln!(md, "- {}{version}{pid}", service.name)?;
+        }
+        for model in &self.ai.ollama_running {
+            writeln!(
+                md,
+                "- Loaded model: {} ({} VRAM)",
+                model.name,
+                format_bytes(model.size_vram)
+            )?;
+        }
+
+        writeln!(md, "\n## Temperatures\n")?;
+        if self.temperature.sensors.is_empty() {
+            writeln!(md, "No sensors read.")?;
+        } else {
+            writeln!(md, "| Sensor | Current | Max |")?;
+            writeln!(md, "|---|---|---|")?;
+            for sensor in &self.temperature.sensors {
+                writeln!(
+                    md,
+                    "| {} | {} | {} |",
+                    sensor.label,
+                    temp_unit.format(sensor.temperature),
+                    temp_unit.format(sensor.max_temperature)
+                )?;
+            }
+        }
+
+        writeln!(md, "\n## Battery\n")?;
+        let bat = &self.battery;
+        if bat.available {
+            let state = if bat.is_charging {
+                "charging"
+            } else if bat.external_connected {
+                "on AC"
+            } else {
+                "on battery"
+            };
+            writeln!(
+                md,
+                "{:.0}%, {state}, health {:.0}%, {} cycles",
+                bat.percent, bat.health_percent, bat.cycle_count
+            )?;
+        } else {
+            writeln!(md, "No battery.")?;
+        }
+
+        Ok(())
+    }
+}
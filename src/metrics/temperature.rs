@@ -1,8 +1,97 @@
 use super::history::History;
+use std::ops::Range;
 use sysinfo::Components;
 
+/// Scale temperatures are shown in. Sensors always read, store and alert
+/// in Celsius.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TempUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnit {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "celsius" | "c" => Some(TempUnit::Celsius),
+            "fahrenheit" | "f" => Some(TempUnit::Fahrenheit),
+            _ => None,
+        }
+    }
+
+    /// `celsius` in this unit.
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            TempUnit::Celsius => celsius,
+            TempUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TempUnit::Celsius => "°C",
+            TempUnit::Fahrenheit => "°F",
+        }
+    }
+
+    /// `celsius` in this unit with one decimal and its symbol, e.g. "45.2°C".
+    pub fn format(self, celsius: f64) -> String {
+        format!("{:.1}{}", self.convert(celsius), self.symbol())
+    }
+}
+
+/// What a sensor measures, guessed from its label. Sensors are listed
+/// grouped in this order.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SensorCategory {
+    Cpu,
+    Gpu,
+    Storage,
+    Battery,
+    Other,
+}
+
+impl SensorCategory {
+    /// Label fragments for each category, checked in order against the
+    /// lowercased sensor label (Apple SMC names first, then Linux drivers).
+    const PATTERNS: [(SensorCategory, &'static [&'static str]); 4] = [
+        (SensorCategory::Gpu, &["gpu", "nouveau", "radeon"]),
+        (
+            SensorCategory::Storage,
+            &["ssd", "nand", "nvme", "disk", "drive"],
+        ),
+        (SensorCategory::Battery, &["battery", "gas gauge", "bat"]),
+        (
+            SensorCategory::Cpu,
+            &[
+                "cpu", "pmu", "tdie", "pacc", "eacc", "soc", "core", "package", "k10temp", "tctl",
+                "tccd",
+            ],
+        ),
+    ];
+
+    pub fn of(label: &str) -> Self {
+        let label = label.to_lowercase();
+        Self::PATTERNS
+            .iter()
+            .find(|(_, needles)| needles.iter().any(|n| label.contains(n)))
+            .map_or(SensorCategory::Other, |(category, _)| *category)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SensorCategory::Cpu => "CPU",
+            SensorCategory::Gpu => "GPU",
+            SensorCategory::Storage => "Storage",
+            SensorCategory::Battery => "Battery",
+            SensorCategory::Other => "Other",
+        }
+    }
+}
+
 pub struct SensorInfo {
     pub label: String,
+    pub category: SensorCategory,
     pub temperature: f64,
     pub max_temperature: f64,
     pub history: History,
@@ -21,22 +110,39 @@ impl TemperatureMetrics {
         }
     }
 
-    pub fn update(&mut self, components: &Components) {
-        let mut added_new = false;
-        for component in components.list() {
-            let label = component.label().to_string();
-            let temp = component.temperature().unwrap_or(0.0) as f64;
-            let max = component.max().unwrap_or(0.0) as f64;
+    /// Read every sensor: hwmon on Linux, where sysinfo's component support
+    /// is spotty, otherwise (or if hwmon has nothing) sysinfo's components.
+    pub fn update(&mut self, components: &mut Components) {
+        let readings = hwmon_readings().unwrap_or_else(|| {
+            components.refresh(true);
+            components
+                .list()
+                .iter()
+                .map(|c| Reading {
+                    label: c.label().to_string(),
+                    temperature: c.temperature().unwrap_or(0.0) as f64,
+                    max: c.max().map(f64::from),
+                })
+                .collect()
+        });
 
+        let mut added_new = false;
+        for Reading {
+            label,
+            temperature: temp,
+            max,
+        } in readings
+        {
             if let Some(sensor) = self.sensors.iter_mut().find(|s| s.label == label) {
                 sensor.temperature = temp;
-                sensor.max_temperature = max;
+                sensor.max_temperature = max.unwrap_or(sensor.max_temperature.max(temp));
                 sensor.history.push(temp);
             } else {
                 let mut sensor = SensorInfo {
+                    category: SensorCategory::of(&label),
                     label,
                     temperature: temp,
-                    max_temperature: max,
+                    max_temperature: max.unwrap_or(temp),
                     history: History::new(),
                 };
                 sensor.history.push(temp);
@@ -47,10 +153,24 @@ impl TemperatureMetrics {
 
         // Only re-sort when a new sensor appears
         if added_new {
-            self.sensors.sort_unstable_by(|a, b| a.label.cmp(&b.label));
+            self.sensors
+                .sort_unstable_by(|a, b| (a.category, &a.label).cmp(&(b.category, &b.label)));
         }
     }
 
+    /// Each category present with its index range in `sensors`, in display
+    /// order.
+    pub fn groups(&self) -> Vec<(SensorCategory, Range<usize>)> {
+        let mut groups: Vec<(SensorCategory, Range<usize>)> = Vec::new();
+        for (i, sensor) in self.sensors.iter().enumerate() {
+            match groups.last_mut() {
+                Some((category, range)) if *category == sensor.category => range.end = i + 1,
+                _ => groups.push((sensor.category, i..i + 1)),
+            }
+        }
+        groups
+    }
+
     pub fn select_next(&mut self) {
         if !self.sensors.is_empty() {
             self.selected_sensor = (self.selected_sensor + 1) % self.sensors.len();
@@ -67,3 +187,80 @@ impl TemperatureMetrics {
         }
     }
 }
+
+/// One sensor sample. `max` is `None` when the source doesn't track the
+/// highest temperature, in which case the session peak is kept instead.
+struct Reading {
+    label: String,
+    temperature: f64,
+    max: Option<f64>,
+}
+
+/// Every `temp*_input` under `/sys/class/hwmon`, labelled "<label> (<chip>)"
+/// (e.g. "Core 0 (coretemp)", "Composite (nvme)"): the sensor name leads so
+/// short bar labels stay distinct, and the chip name still feeds
+/// `SensorCategory::of`. `None` if there are no readable sensors.
+#[cfg(target_os = "linux")]
+fn hwmon_readings() -> Option<Vec<Reading>> {
+    use std::fs;
+
+    let read = |path: &std::path::Path| -> Option<String> {
+        fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+    };
+
+    let mut chips: Vec<_> = fs::read_dir("/sys/class/hwmon")
+        .ok()?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .collect();
+    chips.sort();
+
+    let mut readings: Vec<Reading> = Vec::new();
+    // (chip, sensor) pairs seen so far, to number repeats
+    let mut seen: Vec<(String, String)> = Vec::new();
+    for chip in chips {
+        let chip_name = read(&chip.join("name")).unwrap_or_else(|| "hwmon".to_string());
+        let Ok(entries) = fs::read_dir(&chip) else {
+            continue;
+        };
+        let mut inputs: Vec<String> = entries
+            .filter_map(|e| e.ok()?.file_name().into_string().ok())
+            .filter(|f| f.starts_with("temp") && f.ends_with("_input"))
+            .collect();
+        inputs.sort_by_key(|f| {
+            f.trim_start_matches("temp")
+                .trim_end_matches("_input")
+                .parse::<u32>()
+                .unwrap_or(u32::MAX)
+        });
+
+        for input in inputs {
+            let prefix = input.trim_end_matches("_input");
+            // Values are in millidegrees Celsius
+            let Some(millis) = read(&chip.join(&input)).and_then(|v| v.parse::<i64>().ok()) else {
+                continue;
+            };
+            let sensor =
+                read(&chip.join(format!("{prefix}_label"))).unwrap_or_else(|| prefix.to_string());
+            // Identical chips (e.g. two NVMe drives) report identical labels
+            let key = (chip_name.clone(), sensor.clone());
+            let same = seen.iter().filter(|k| **k == key).count();
+            seen.push(key);
+            let label = if same > 0 {
+                format!("{sensor} #{} ({chip_name})", same + 1)
+            } else {
+                format!("{sensor} ({chip_name})")
+            };
+            readings.push(Reading {
+                label,
+                temperature: millis as f64 / 1000.0,
+                max: None,
+            });
+        }
+    }
+    (!readings.is_empty()).then_some(readings)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn hwmon_readings() -> Option<Vec<Reading>> {
+    None
+}
@@ -0,0 +1,70 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::metrics::MetricsCollector;
+
+const HEADER: &str = "timestamp,cpu_percent,mem_percent,swap_percent,rx_bytes_per_sec,tx_bytes_per_sec,gpu_percent,battery_percent";
+
+/// Flush to disk every this many rows so a crash loses at most a few samples.
+const FLUSH_EVERY: u32 = 10;
+
+/// Appends one CSV row of headline metrics per refresh.
+pub struct Recorder {
+    writer: BufWriter<File>,
+    pending: u32,
+}
+
+impl Recorder {
+    /// Open `path` for appending, writing the header row if the file is new or empty.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let is_empty = file.metadata()?.len() == 0;
+        let mut writer = BufWriter::new(file);
+        if is_empty {
+            writeln!(writer, "{HEADER}")?;
+            writer.flush()?;
+        }
+        Ok(Self { writer, pending: 0 })
+    }
+
+    pub fn record(&mut self, metrics: &MetricsCollector) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+        let battery = if metrics.battery.available {
+            format!("{:.1}", metrics.battery.percent)
+        } else {
+            String::new()
+        };
+
+        // The same device the dashboard and report show
+        let gpu = metrics.gpu.primary().map_or(0.0, |d| d.device_utilization);
+
+        writeln!(
+            self.writer,
+            "{:.3},{:.1},{:.1},{:.1},{:.0},{:.0},{:.1},{}",
+            timestamp,
+            metrics.cpu.aggregate_usage,
+            metrics.memory.ram_percent,
+            metrics.memory.swap_percent,
+            metrics.network.total_rx_rate,
+            metrics.network.total_tx_rate,
+            gpu,
+            battery,
+        )?;
+
+        self.pending += 1;
+        if self.pending >= FLUSH_EVERY {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.pending = 0;
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,215 @@
+use color_eyre::eyre::WrapErr;
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::metrics::MetricsCollector;
+use crate::util::{format_bytes, local_datetime, write_string};
+
+/// A point-in-time copy of the headline metrics, saved as JSON by
+/// `--snapshot` and compared by `--diff`.
+#[derive(Serialize, Deserialize)]
+pub struct Snapshot {
+    pub hostname: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub cpu_percent: f64,
+    pub mem_used: u64,
+    pub mem_total: u64,
+    pub swap_used: u64,
+    pub disks: Vec<DiskSnapshot>,
+    pub processes: Vec<ProcessSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DiskSnapshot {
+    pub mount_point: String,
+    pub total_space: u64,
+    pub available_space: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessSnapshot {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_usage: f64,
+    pub memory: u64,
+}
+
+/// New/gone process lists are cut off after this many entries.
+const MAX_LISTED_PROCESSES: usize = 20;
+
+impl Snapshot {
+    pub fn capture(metrics: &MetricsCollector, hostname: String, timestamp: u64) -> Self {
+        Self {
+            hostname,
+            timestamp,
+            cpu_percent: metrics.cpu.aggregate_usage,
+            mem_used: metrics.memory.used_ram,
+            mem_total: metrics.memory.total_ram,
+            swap_used: metrics.memory.used_swap,
+            disks: metrics
+                .disk
+                .disks
+                .iter()
+                .map(|d| DiskSnapshot {
+                    mount_point: d.mount_point.clone(),
+                    total_space: d.total_space,
+                    available_space: d.available_space,
+                })
+                .collect(),
+            processes: metrics
+                .processes
+                .processes
+                .iter()
+                .map(|p| ProcessSnapshot {
+                    pid: p.pid,
+                    name: p.name.clone(),
+                    cpu_usage: p.cpu_usage,
+                    memory: p.memory,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).wrap_err_with(|| format!("writing {}", path.display()))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json =
+            fs::read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&json).wrap_err_with(|| format!("parsing {}", path.display()))
+    }
+
+    /// Human-readable deltas from `self` (before) to `after`.
+    pub fn diff(&self, after: &Snapshot) -> String {
+        write_string(|out| self.write_diff(out, after))
+    }
+
+    fn write_diff(&self, out: &mut String, after: &Snapshot) -> std::fmt::Result {
+        writeln!(
+            out,
+            "Comparing {} ({}) -> {} ({})",
+            self.hostname,
+            local_datetime(self.timestamp),
+            after.hostname,
+            local_datetime(after.timestamp)
+        )?;
+
+        writeln!(out, "\nCPU")?;
+        writeln!(
+            out,
+            "  usage   {:.1}% -> {:.1}% ({:+.1} pts)",
+            self.cpu_percent,
+            after.cpu_percent,
+            after.cpu_percent - self.cpu_percent
+        )?;
+
+        writeln!(out, "\nMemory")?;
+        writeln!(
+            out,
+            "  used    {} -> {} ({})",
+            format_bytes(self.mem_used),
+            format_bytes(after.mem_used),
+            signed_bytes(after.mem_used, self.mem_used)
+        )?;
+        writeln!(
+            out,
+            "  swap    {} -> {} ({})",
+            format_bytes(self.swap_used),
+            format_bytes(after.swap_used),
+            signed_bytes(after.swap_used, self.swap_used)
+        )?;
+
+        writeln!(out, "\nDisks")?;
+        for disk in &after.disks {
+            let used = disk.total_space.saturating_sub(disk.available_space);
+            match self
+                .disks
+                .iter()
+                .find(|d| d.mount_point == disk.mount_point)
+            {
+                Some(before) => {
+                    let before_used = before.total_space.saturating_sub(before.available_space);
+                    writeln!(
+                        out,
+                        "  {}  {} -> {} used ({})",
+                        disk.mount_point,
+                        format_bytes(before_used),
+                        format_bytes(used),
+                        signed_bytes(used, before_used)
+                    )?;
+                }
+                None => writeln!(
+                    out,
+                    "  {}  mounted ({} used)",
+                    disk.mount_point,
+                    format_bytes(used)
+                )?,
+            }
+        }
+        for disk in &self.disks {
+            if !after
+                .disks
+                .iter()
+                .any(|d| d.mount_point == disk.mount_point)
+            {
+                writeln!(out, "  {}  unmounted", disk.mount_point)?;
+            }
+        }
+
+        // A PID can be reused, so identify processes by PID and name together
+        let key = |p: &ProcessSnapshot| (p.pid, p.name.clone());
+        let before_keys: HashSet<_> = self.processes.iter().map(key).collect();
+        let after_keys: HashSet<_> = after.processes.iter().map(key).collect();
+
+        let mut started: Vec<_> = after
+            .processes
+            .iter()
+            .filter(|p| !before_keys.contains(&key(p)))
+            .collect();
+        let mut exited: Vec<_> = self
+            .processes
+            .iter()
+            .filter(|p| !after_keys.contains(&key(p)))
+            .collect();
+        started.sort_by_key(|p| Reverse(p.memory));
+        exited.sort_by_key(|p| Reverse(p.memory));
+
+        write_processes(out, "New processes", &started)?;
+        write_processes(out, "Gone processes", &exited)?;
+        Ok(())
+    }
+}
+
+fn write_processes(out: &mut String, title: &str, procs: &[&ProcessSnapshot]) -> std::fmt::Result {
+    writeln!(out, "\n{title} ({})", procs.len())?;
+    for p in procs.iter().take(MAX_LISTED_PROCESSES) {
+        writeln!(
+            out,
+            "  {:>7}  {:<24} {:>10}",
+            p.pid,
+            p.name,
+            format_bytes(p.memory)
+        )?;
+    }
+    if procs.len() > MAX_LISTED_PROCESSES {
+        writeln!(out, "  ... {} more", procs.len() - MAX_LISTED_PROCESSES)?;
+    }
+    Ok(())
+}
+
+/// `after - before` as a byte size with an explicit sign.
+fn signed_bytes(after: u64, before: u64) -> String {
+    if after >= before {
+        format!("+{}", format_bytes(after - before))
+    } else {
+        format!("-{}", format_bytes(before - after))
+    }
+}
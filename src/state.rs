@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::ui::tabs::Tab;
+
+/// UI state remembered between runs, kept in
+/// `$XDG_STATE_HOME/peakmon/state.toml` (falling back to `~/.local/state`).
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct State {
+    /// Name of the tab open at the last quit.
+    pub last_tab: Option<String>,
+}
+
+impl State {
+    /// The saved state, or the default if there is none or it can't be read.
+    pub fn load() -> Self {
+        state_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| toml::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = state_path().ok_or_else(|| io::Error::other("HOME is not set"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, text)
+    }
+
+    pub fn last_tab(&self) -> Option<Tab> {
+        self.last_tab.as_deref().and_then(Tab::from_name)
+    }
+}
+
+fn state_path() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_STATE_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?)
+            .join(".local")
+            .join("state"),
+    };
+    Some(base.join("peakmon").join("state.toml"))
+}
@@ -1,11 +1,13 @@
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use super::tabs::Tab;
 use super::theme;
+use crate::app::RefreshPreset;
 use crate::metrics::ai::{AiMetrics, ChatStatus};
 
 #[allow(clippy::too_many_arguments)]
@@ -14,12 +16,43 @@ pub fn render(
     area: Rect,
     current_tab: Tab,
     filter_mode: bool,
+    search_mode: bool,
+    finding: bool,
+    goto_mode: bool,
     filter_buffer: &str,
     refresh_rate: Duration,
+    idle: bool,
     ai: &AiMetrics,
-    copy_feedback: Option<Instant>,
 ) {
-    let hints = if filter_mode {
+    let hints = if search_mode {
+        let display = if filter_buffer.is_empty() {
+            "type to find".to_string()
+        } else {
+            filter_buffer.to_string()
+        };
+        vec![
+            Span::styled(" find: ", theme::key_hint_style()),
+            Span::styled(format!("{display}_ "), theme::value_style()),
+            Span::styled(" Enter", theme::key_hint_style()),
+            Span::styled(" jump  ", theme::label_style()),
+            Span::styled("Esc", theme::key_hint_style()),
+            Span::styled(" cancel", theme::label_style()),
+        ]
+    } else if goto_mode {
+        let display = if filter_buffer.is_empty() {
+            "line number".to_string()
+        } else {
+            filter_buffer.to_string()
+        };
+        vec![
+            Span::styled(" :", theme::key_hint_style()),
+            Span::styled(format!("{display}_ "), theme::value_style()),
+            Span::styled(" Enter", theme::key_hint_style()),
+            Span::styled(" jump  ", theme::label_style()),
+            Span::styled("Esc", theme::key_hint_style()),
+            Span::styled(" cancel", theme::label_style()),
+        ]
+    } else if filter_mode {
         let display = if filter_buffer.is_empty() {
             "type to filter".to_string()
         } else {
@@ -41,7 +74,7 @@ pub fn render(
             Span::styled(" help  ", theme::label_style()),
             Span::styled("Tab", theme::key_hint_style()),
             Span::styled(" switch  ", theme::label_style()),
-            Span::styled("+/-", theme::key_hint_style()),
+            Span::styled("+/-/*", theme::key_hint_style()),
             Span::styled(" rate  ", theme::label_style()),
         ];
 
@@ -50,16 +83,50 @@ pub fn render(
                 h.extend([
                     Span::styled("/", theme::key_hint_style()),
                     Span::styled(" filter  ", theme::label_style()),
+                    Span::styled("f", theme::key_hint_style()),
+                    Span::styled(" find  ", theme::label_style()),
                     Span::styled("c", theme::key_hint_style()),
                     Span::styled(" cpu  ", theme::label_style()),
                     Span::styled("m", theme::key_hint_style()),
                     Span::styled(" mem  ", theme::label_style()),
+                    Span::styled("e", theme::key_hint_style()),
+                    Span::styled(" energy  ", theme::label_style()),
+                    Span::styled("S", theme::key_hint_style()),
+                    Span::styled(" state  ", theme::label_style()),
                     Span::styled("p", theme::key_hint_style()),
                     Span::styled(" pid  ", theme::label_style()),
-                    Span::styled("n", theme::key_hint_style()),
-                    Span::styled(" name  ", theme::label_style()),
+                ]);
+                // While a find query is set, n/N step through matches
+                if finding {
+                    h.extend([
+                        Span::styled("n/N", theme::key_hint_style()),
+                        Span::styled(" next/prev  ", theme::label_style()),
+                        Span::styled("Esc", theme::key_hint_style()),
+                        Span::styled(" end find  ", theme::label_style()),
+                    ]);
+                } else {
+                    h.extend([
+                        Span::styled("n", theme::key_hint_style()),
+                        Span::styled(" name  ", theme::label_style()),
+                    ]);
+                }
+                h.extend([
                     Span::styled("t", theme::key_hint_style()),
                     Span::styled(" tree  ", theme::label_style()),
+                    Span::styled("s", theme::key_hint_style()),
+                    Span::styled(" system  ", theme::label_style()),
+                    Span::styled("u", theme::key_hint_style()),
+                    Span::styled(" mine  ", theme::label_style()),
+                    Span::styled("F", theme::key_hint_style()),
+                    Span::styled(" follow  ", theme::label_style()),
+                    Span::styled("Enter", theme::key_hint_style()),
+                    Span::styled(" inspect  ", theme::label_style()),
+                    Span::styled("</>", theme::key_hint_style()),
+                    Span::styled(" renice  ", theme::label_style()),
+                    Span::styled("y/Y", theme::key_hint_style()),
+                    Span::styled(" copy pid/cmd  ", theme::label_style()),
+                    Span::styled("x", theme::key_hint_style()),
+                    Span::styled(" signal  ", theme::label_style()),
                     Span::styled("K", theme::key_hint_style()),
                     Span::styled(" kill", theme::label_style()),
                 ]);
@@ -70,8 +137,22 @@ pub fn render(
                     Span::styled(" filter  ", theme::label_style()),
                     Span::styled("l", theme::key_hint_style()),
                     Span::styled(" level  ", theme::label_style()),
+                    Span::styled(":", theme::key_hint_style()),
+                    Span::styled(" line  ", theme::label_style()),
                     Span::styled("a", theme::key_hint_style()),
-                    Span::styled(" autoscroll", theme::label_style()),
+                    Span::styled(" autoscroll  ", theme::label_style()),
+                    Span::styled("t", theme::key_hint_style()),
+                    Span::styled(" relative time  ", theme::label_style()),
+                    Span::styled("w", theme::key_hint_style()),
+                    Span::styled(" wrap  ", theme::label_style()),
+                    Span::styled("p", theme::key_hint_style()),
+                    Span::styled(" pause on error  ", theme::label_style()),
+                    Span::styled("y", theme::key_hint_style()),
+                    Span::styled(" copy  ", theme::label_style()),
+                    Span::styled("E", theme::key_hint_style()),
+                    Span::styled(" export  ", theme::label_style()),
+                    Span::styled("C", theme::key_hint_style()),
+                    Span::styled(" clear", theme::label_style()),
                 ]);
             }
             Tab::Ai => {
@@ -81,46 +162,74 @@ pub fn render(
                         Span::styled(" cancel generation", theme::label_style()),
                     ]);
                 } else {
-                    h.extend([
-                        Span::styled("j/k", theme::key_hint_style()),
-                        Span::styled(" select  ", theme::label_style()),
-                        Span::styled("i", theme::key_hint_style()),
-                        Span::styled(" chat  ", theme::label_style()),
-                        Span::styled("S", theme::key_hint_style()),
-                        Span::styled(" search  ", theme::label_style()),
-                        Span::styled("D", theme::key_hint_style()),
-                        Span::styled(" delete  ", theme::label_style()),
-                        Span::styled("U", theme::key_hint_style()),
-                        Span::styled(" unload  ", theme::label_style()),
-                        Span::styled("C", theme::key_hint_style()),
-                        Span::styled(" clear  ", theme::label_style()),
-                        Span::styled("y", theme::key_hint_style()),
-                        Span::styled(" copy  ", theme::label_style()),
-                        Span::styled("Y", theme::key_hint_style()),
-                        Span::styled(" copy all", theme::label_style()),
-                    ]);
+                    if ai.is_pulling() {
+                        h.extend([
+                            Span::styled("Esc", theme::key_hint_style()),
+                            Span::styled(" stop pull  ", theme::label_style()),
+                        ]);
+                    }
+                    // Keys that would do nothing right now are dimmed
+                    let has_model = ai.selected_model_name().is_some();
+                    let has_chat = !ai.chat_messages.is_empty();
+                    h.extend(hint("j/k", " select  ", true));
+                    h.extend(hint("i", " chat  ", ai.has_loaded_model()));
+                    h.extend(hint("B", " bench  ", has_model));
+                    h.extend(hint("S", " search  ", ai.ollama_available));
+                    h.extend(hint("D", " delete  ", has_model));
+                    h.extend(hint("P", " pin  ", has_model));
+                    h.extend(hint("U", " unload  ", has_model));
+                    h.extend(hint("C", " clear  ", has_chat));
+                    h.extend(hint("y", " copy  ", has_chat));
+                    h.extend(hint("Y", " copy all", has_chat));
                 }
             }
+            Tab::Dashboard => {
+                h.extend([
+                    Span::styled("d", theme::key_hint_style()),
+                    Span::styled(" compact", theme::label_style()),
+                ]);
+            }
+            Tab::Disk => {
+                h.extend([
+                    Span::styled("u", theme::key_hint_style()),
+                    Span::styled(" used%  ", theme::label_style()),
+                    Span::styled("s", theme::key_hint_style()),
+                    Span::styled(" size  ", theme::label_style()),
+                    Span::styled("i", theme::key_hint_style()),
+                    Span::styled(" I/O", theme::label_style()),
+                ]);
+            }
             Tab::Temperatures => {
                 h.extend([
                     Span::styled("j/k", theme::key_hint_style()),
                     Span::styled(" select sensor", theme::label_style()),
                 ]);
             }
-            _ => {
+            Tab::Gpu => {
                 h.extend([
                     Span::styled("j/k", theme::key_hint_style()),
-                    Span::styled(" scroll", theme::label_style()),
+                    Span::styled(" select GPU", theme::label_style()),
                 ]);
             }
-        }
-        // Show "Copied!" feedback for 2 seconds after a copy
-        if let Some(t) = copy_feedback {
-            if t.elapsed() < Duration::from_secs(2) {
-                h.push(Span::styled("  ✓ Copied!", theme::value_style()));
+            Tab::Network => {
+                h.extend([
+                    Span::styled("j/k", theme::key_hint_style()),
+                    Span::styled(" select interface  ", theme::label_style()),
+                    Span::styled("a", theme::key_hint_style()),
+                    Span::styled(" all interfaces  ", theme::label_style()),
+                    Span::styled("T", theme::key_hint_style()),
+                    Span::styled(" bandwidth test  ", theme::label_style()),
+                    Span::styled("L", theme::key_hint_style()),
+                    Span::styled(" line chart", theme::label_style()),
+                ]);
+            }
+            Tab::Cpu | Tab::Memory => {
+                h.extend([
+                    Span::styled("L", theme::key_hint_style()),
+                    Span::styled(" line chart", theme::label_style()),
+                ]);
             }
         }
-
         h
     };
 
@@ -138,8 +247,16 @@ pub fn render(
     } else {
         format!("{rate_ms}ms ")
     };
+    // Idle mode slows refreshing until the next key or mouse event
+    let rate_label = if idle {
+        Span::styled("idle ", Style::default().fg(theme::YELLOW))
+    } else if let Some(preset) = RefreshPreset::from_rate(refresh_rate) {
+        Span::styled(format!("{} ", preset.label()), theme::label_style())
+    } else {
+        Span::styled("refresh ", theme::label_style())
+    };
     let rate_line = Line::from(vec![
-        Span::styled("refresh ", theme::label_style()),
+        rate_label,
         Span::styled(rate_text, theme::value_style()),
     ]);
     frame.render_widget(
@@ -149,3 +266,16 @@ pub fn render(
         chunks[1],
     );
 }
+
+/// A key hint and its label, grayed out when the key can't act right now.
+fn hint(key: &'static str, label: &'static str, enabled: bool) -> [Span<'static>; 2] {
+    if enabled {
+        [
+            Span::styled(key, theme::key_hint_style()),
+            Span::styled(label, theme::label_style()),
+        ]
+    } else {
+        let dim = Style::default().fg(theme::SURFACE1);
+        [Span::styled(key, dim), Span::styled(label, dim)]
+    }
+}
@@ -1,28 +1,49 @@
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::tabs::Tab;
 use super::theme;
-use crate::util::format_uptime;
+use crate::util::{format_age, format_bytes, format_uptime, local_clock, now_secs};
 
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     frame: &mut Frame,
     area: Rect,
     current_tab: Tab,
     hostname: &str,
     uptime_secs: u64,
+    awake_secs: Option<u64>,
     load_avg: [f64; 3],
+    alerted_tabs: &[Tab],
+    data_age: Option<Duration>,
+    stale: bool,
+    own_usage: Option<(f64, u64)>,
 ) {
-    let chunks = Layout::default()
+    let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(1), Constraint::Length(1)])
         .split(area);
+    // Right-hand columns: wall clock on top, sample age below
+    let [info_area, clock_area] = split_right(rows[0], 10);
+    let [tabs_area, age_area] = split_right(rows[1], 18);
 
     // Top line: app name + hostname + uptime
-    let uptime_str = format_uptime(uptime_secs);
-    let info_line = Line::from(vec![
+    let uptime_str = match awake_secs {
+        // Only worth showing when the machine has actually slept
+        Some(awake) if uptime_secs.saturating_sub(awake) >= 60 => {
+            format!(
+                "{} (awake {})",
+                format_uptime(uptime_secs),
+                format_uptime(awake)
+            )
+        }
+        _ => format_uptime(uptime_secs),
+    };
+    let mut info_spans = vec![
         Span::styled(" peakmon ", theme::title_style()),
         Span::styled(format!("  {hostname}"), theme::value_style()),
         Span::styled(format!("  up {uptime_str}"), theme::label_style()),
@@ -33,11 +54,36 @@ pub fn render(
             ),
             theme::label_style(),
         ),
-    ]);
+    ];
+    // What peakmon itself costs, e.g. at a high refresh rate
+    if let Some((cpu, mem)) = own_usage {
+        info_spans.push(Span::styled(
+            format!("  self {cpu:.1}% {}", format_bytes(mem)),
+            theme::label_style(),
+        ));
+    }
+    let info_line = Line::from(info_spans);
     frame.render_widget(
         Paragraph::new(info_line).style(theme::header_style()),
-        chunks[0],
+        info_area,
     );
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            format!("{} ", local_clock(now_secs())),
+            theme::value_style(),
+        ))
+        .alignment(Alignment::Right)
+        .style(theme::header_style()),
+        clock_area,
+    );
+
+    // Alerted tabs flash red at ~1Hz
+    let flash_on = (SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        / 500)
+        .is_multiple_of(2);
 
     // Tab bar
     let mut tab_spans = vec![Span::raw(" ")];
@@ -49,7 +95,9 @@ pub fn render(
             num.to_string()
         };
         let label = format!(" {display_num}:{} ", tab.label());
-        if *tab == current_tab {
+        if alerted_tabs.contains(tab) && flash_on {
+            tab_spans.push(Span::styled(label, theme::alert_tab_style()));
+        } else if *tab == current_tab {
             tab_spans.push(Span::styled(label, theme::active_tab_style()));
         } else {
             tab_spans.push(Span::styled(label, theme::inactive_tab_style()));
@@ -59,6 +107,48 @@ pub fn render(
     let tab_line = Line::from(tab_spans);
     frame.render_widget(
         Paragraph::new(tab_line).style(theme::header_style()),
-        chunks[1],
+        tabs_area,
     );
+
+    // Tabs refresh lazily, so say how old the numbers on screen are and
+    // flag them until the tab's subsystem has caught up
+    let age = data_age.map(|age| format_age(age.as_secs()));
+    let age_span = match age {
+        Some(age) if stale => Span::styled(
+            format!("stale ({age}) "),
+            Style::default().fg(theme::YELLOW),
+        ),
+        Some(age) => Span::styled(format!("updated {age} "), theme::label_style()),
+        None => Span::raw(""),
+    };
+    frame.render_widget(
+        Paragraph::new(age_span)
+            .alignment(Alignment::Right)
+            .style(theme::header_style()),
+        age_area,
+    );
+}
+
+/// One-line stand-in for the header while it's hidden with `H`.
+pub fn render_status(frame: &mut Frame, area: Rect, current_tab: Tab) {
+    let line = Line::from(vec![
+        Span::styled(
+            format!(
+                " {}:{} ",
+                (current_tab.index() + 1) % 10,
+                current_tab.label()
+            ),
+            theme::active_tab_style(),
+        ),
+        Span::styled("  H shows header and footer", theme::label_style()),
+    ]);
+    frame.render_widget(Paragraph::new(line).style(theme::header_style()), area);
+}
+
+/// Split `area` into the remaining left part and `width` columns on the right.
+fn split_right(area: Rect, width: u16) -> [Rect; 2] {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(width)])
+        .areas(area)
 }
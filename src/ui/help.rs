@@ -3,65 +3,205 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 
+use super::tabs::Tab;
 use super::theme;
+use super::widgets::scrollbar;
 
-pub fn render(frame: &mut Frame, area: Rect) {
-    let popup = centered_rect(60, 33, area);
+/// Tab-specific keys, shown first when help is opened on that tab.
+fn tab_section(tab: Tab) -> Option<(&'static str, &'static [(&'static str, &'static str)])> {
+    let keys: &[(&str, &str)] = match tab {
+        Tab::Dashboard => &[("d", "Toggle compact layout")],
+        Tab::Processes => &[
+            (
+                "c / m / p / n",
+                "Sort by CPU / Mem / PID / Name (n: unless finding)",
+            ),
+            ("e", "Sort by estimated energy impact"),
+            ("S", "Sort by state (Run, Sleep, Idle, Stop, Zombie)"),
+            ("f", "Find process (jump, keeps list)"),
+            ("~", "Toggle fuzzy filter matching (ranks by match)"),
+            ("n / N", "Next / previous match (while finding; Esc ends)"),
+            ("t", "Toggle tree view"),
+            ("s", "Hide / show system processes"),
+            ("u", "Show only my processes"),
+            ("F", "Follow selected process as it re-sorts"),
+            ("Left / Right", "Scroll columns (PID/Name pinned)"),
+            ("Enter", "Inspect selected process"),
+            ("< / >", "Renice selected (raise / lower priority)"),
+            ("y / Y", "Copy selected PID / command line"),
+            ("K", "Kill selected process (SIGTERM)"),
+            ("x", "Send a signal (TERM, KILL, INT, HUP, STOP, CONT)"),
+        ],
+        Tab::Ai => &[
+            ("j / k", "Select model / scroll chat"),
+            ("i", "Enter chat prompt"),
+            ("B", "Benchmark selected model (fixed prompt)"),
+            ("S", "Search Ollama library"),
+            ("D", "Delete selected model"),
+            ("Enter", "Load selected model"),
+            ("P", "Pin / unpin selected model in memory"),
+            ("U", "Unload selected model"),
+            ("C", "Clear chat history"),
+            ("Esc", "Cancel generation / stop pull"),
+        ],
+        Tab::Gpu => &[("j / k", "Select GPU (multi-GPU Macs)")],
+        Tab::Network => &[
+            ("j / k", "Select interface"),
+            ("a", "Show all / only active interfaces"),
+            ("T", "Start / stop a bandwidth test (tracks peak RX/TX)"),
+            ("L", "Toggle line chart of the full history"),
+        ],
+        Tab::Logs => &[
+            ("l", "Cycle log level filter"),
+            (":", "Jump to line number"),
+            ("a", "Toggle auto-scroll"),
+            ("t", "Toggle relative timestamps"),
+            ("w", "Toggle line wrap (←/→ scroll when off)"),
+            ("p", "Pause on new ERROR/FAULT (any key resumes)"),
+            ("y", "Copy selected line"),
+            ("E", "Export filtered view to a file"),
+            ("C", "Clear log buffer"),
+            ("r", "Restart the log stream"),
+        ],
+        Tab::Disk => &[("u / s / i", "Sort by used% / size / I/O (again to reverse)")],
+        Tab::Temperatures => &[("j / k", "Select sensor")],
+        Tab::Cpu | Tab::Memory => &[("L", "Toggle line chart of the full history")],
+    };
+    Some((tab.label(), keys))
+}
+
+const GLOBAL_SECTIONS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Navigation",
+        &[
+            ("1-9, 0", "Switch to tab by number"),
+            ("Tab / Shift+Tab", "Cycle through tabs"),
+            ("F1-F10", "Switch to tab by function key"),
+        ],
+    ),
+    (
+        "Scrolling",
+        &[
+            ("j / Down", "Scroll down / select next"),
+            ("k / Up", "Scroll up / select previous"),
+            ("g / G", "Jump to top / bottom"),
+            ("PgDn / PgUp", "Page down / page up"),
+            ("Ctrl+D / Ctrl+U", "Half page down / up"),
+        ],
+    ),
+    (
+        "General",
+        &[
+            ("+/-", "Increase / decrease refresh rate"),
+            ("*", "Cycle refresh presets (fast / normal / slow)"),
+            ("z", "Zoom the main panel (detail tabs)"),
+            ("H", "Hide / show the header and footer"),
+            ("b", "Toggle braille (hi-res) sparklines"),
+            ("v", "Swap average / max when sparklines are downsampled"),
+            ("#", "Toggle binary (GiB) / decimal (GB) units"),
+            ("%", "Toggle CPU usage per core average / total (like top)"),
+            ("A", "Toggle smoothed CPU sparklines"),
+            ("M", "Write a Markdown system report"),
+            ("I", "Copy a one-line system summary"),
+            ("R", "Reset sparkline histories"),
+            ("/", "Filter (Processes & Logs)"),
+            ("?", "Toggle this help"),
+            ("q / Ctrl+C", "Quit"),
+        ],
+    ),
+];
+
+/// Help text for `current`: its own keys first, then the global keys, then
+/// every other tab's keys.
+fn help_lines(current: Tab) -> Vec<Line<'static>> {
+    help_text(current).0
+}
+
+/// Line index of each section header in the help text, for jumping between
+/// sections.
+pub fn section_starts(current: Tab) -> Vec<usize> {
+    help_text(current).1
+}
+
+fn help_text(current: Tab) -> (Vec<Line<'static>>, Vec<usize>) {
+    let mut sections = Vec::new();
+    sections.extend(tab_section(current).map(|(name, keys)| (format!("{name} Tab"), keys)));
+    sections.extend(
+        GLOBAL_SECTIONS
+            .iter()
+            .map(|&(name, keys)| (name.to_string(), keys)),
+    );
+    sections.extend(
+        Tab::ALL
+            .into_iter()
+            .filter(|&t| t != current)
+            .filter_map(tab_section)
+            .map(|(name, keys)| (format!("{name} Tab"), keys)),
+    );
+
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    for (i, (name, keys)) in sections.into_iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::raw(""));
+        }
+        starts.push(lines.len());
+        lines.push(header_line(name));
+        lines.extend(keys.iter().map(|&(key, desc)| key_line(key, desc)));
+    }
+    (lines, starts)
+}
+
+/// Popup height for the help text, shrunk to fit the terminal.
+fn popup_height(line_count: usize, area: Rect) -> u16 {
+    (line_count as u16 + 2).min(area.height)
+}
+
+/// Furthest the help text can scroll on a terminal of this size.
+pub fn max_scroll(current: Tab, area: Rect) -> usize {
+    let total = help_lines(current).len();
+    let visible = popup_height(total, area).saturating_sub(2) as usize;
+    total.saturating_sub(visible)
+}
+
+pub fn render(frame: &mut Frame, area: Rect, current: Tab, scroll: usize) {
+    let lines = help_lines(current);
+    let total = lines.len();
+    let popup = centered_rect(60, popup_height(total, area), area);
     frame.render_widget(Clear, popup);
 
-    let lines = vec![
-        header_line("Navigation"),
-        key_line("1-9, 0", "Switch to tab by number"),
-        key_line("Tab / Shift+Tab", "Cycle through tabs"),
-        key_line("F1-F10", "Switch to tab by function key"),
-        Line::raw(""),
-        header_line("Scrolling"),
-        key_line("j / Down", "Scroll down / select next"),
-        key_line("k / Up", "Scroll up / select previous"),
-        key_line("g / G", "Jump to top / bottom"),
-        key_line("PgDn / PgUp", "Page down / page up"),
-        Line::raw(""),
-        header_line("General"),
-        key_line("+/-", "Increase / decrease refresh rate"),
-        key_line("/", "Filter (Processes & Logs)"),
-        key_line("?", "Toggle this help"),
-        key_line("q / Ctrl+C", "Quit"),
-        Line::raw(""),
-        header_line("Processes Tab"),
-        key_line("c / m / p / n", "Sort by CPU / Mem / PID / Name"),
-        key_line("t", "Toggle tree view"),
-        key_line("K", "Kill selected process (SIGTERM)"),
-        Line::raw(""),
-        header_line("AI Tab"),
-        key_line("j / k", "Select model / scroll chat"),
-        key_line("i", "Enter chat prompt"),
-        key_line("S", "Search Ollama library"),
-        key_line("D", "Delete selected model"),
-        key_line("Enter", "Load selected model"),
-        key_line("U", "Unload selected model"),
-        key_line("C", "Clear chat history"),
-        key_line("Esc", "Cancel generation"),
-        Line::raw(""),
-        header_line("Logs Tab"),
-        key_line("l", "Cycle log level filter"),
-        key_line("a", "Toggle auto-scroll"),
-    ];
+    let visible = popup.height.saturating_sub(2) as usize;
+    let scroll = scroll.min(total.saturating_sub(visible));
+
+    let more = if scroll < total.saturating_sub(visible) {
+        Line::styled(" more \u{2193} ", theme::key_hint_style()).right_aligned()
+    } else {
+        Line::default()
+    };
 
     let block = Block::default()
         .title(Line::styled(" Help ", theme::title_style()))
+        .title_bottom(Line::styled(
+            " j/k scroll  n/N section  ?/Esc close ",
+            theme::label_style(),
+        ))
+        .title_bottom(more)
         .borders(Borders::ALL)
         .border_style(theme::border_style())
         .style(ratatui::style::Style::default().bg(theme::BASE));
 
-    let paragraph = Paragraph::new(lines).block(block);
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((scroll as u16, 0));
     frame.render_widget(paragraph, popup);
+    scrollbar::render(frame, popup, scroll, visible, total);
 }
 
-fn header_line(text: &str) -> Line<'_> {
+fn header_line(text: String) -> Line<'static> {
     Line::from(Span::styled(format!("  {text}"), theme::title_style()))
 }
 
-fn key_line<'a>(key: &'a str, desc: &'a str) -> Line<'a> {
+fn key_line(key: &'static str, desc: &'static str) -> Line<'static> {
     Line::from(vec![
         Span::styled(format!("    {key:<20}"), theme::key_hint_style()),
         Span::styled(desc, theme::label_style()),
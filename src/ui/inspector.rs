@@ -0,0 +1,163 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::theme;
+use super::widgets::sparkline_panel;
+use super::RenderOptions;
+use crate::logs::stream::LogStream;
+use crate::metrics::ai::is_ai_process;
+use crate::metrics::MetricsCollector;
+use crate::util::{format_bytes, format_percent};
+
+/// Consolidated view of a single process: stats, history, command line and
+/// any log lines it has emitted.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    pid: u32,
+    metrics: &MetricsCollector,
+    log_stream: &LogStream,
+    opts: RenderOptions,
+) {
+    let popup = super::centered_rect(80, 24, area);
+    frame.render_widget(Clear, popup);
+
+    let proc = metrics.processes.processes.iter().find(|p| p.pid == pid);
+    let inspected = metrics.processes.inspected.as_ref();
+    let alive = inspected.is_none_or(|i| i.alive) && proc.is_some();
+    let name = proc.map(|p| p.name.as_str()).unwrap_or("?");
+
+    let status = if alive { "" } else { " (exited)" };
+    let block = Block::default()
+        .title(Line::styled(
+            format!(" Inspect: {name} (PID {pid}){status} "),
+            theme::title_style(),
+        ))
+        .title_bottom(Line::styled(" any key to close ", theme::label_style()))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Length(2), // Command line
+            Constraint::Length(5), // CPU history
+            Constraint::Length(5), // Memory history
+            Constraint::Min(3),    // Related log lines
+        ])
+        .split(inner);
+
+    // Summary
+    let mut summary = vec![Span::raw(" ")];
+    if let Some(p) = proc {
+        let parent = p
+            .parent_pid
+            .map(|pp| pp.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        summary.extend([
+            Span::styled("State ", theme::label_style()),
+            Span::styled(
+                p.status.label(),
+                Style::default().fg(theme::process_state_color(p.status, opts.palette())),
+            ),
+            Span::styled("  Parent ", theme::label_style()),
+            Span::styled(parent, theme::value_style()),
+            Span::styled("  Threads ", theme::label_style()),
+            Span::styled(p.thread_count.to_string(), theme::value_style()),
+            Span::styled("  CPU ", theme::label_style()),
+            Span::styled(format_percent(p.cpu_usage), theme::value_style()),
+            Span::styled("  Mem ", theme::label_style()),
+            Span::styled(format_bytes(p.memory), theme::value_style()),
+        ]);
+    }
+    let ai_label = if is_ai_process(name) { "yes" } else { "no" };
+    let summary_lines = vec![
+        Line::from(summary),
+        Line::from(vec![
+            Span::styled(" AI process ", theme::label_style()),
+            Span::styled(ai_label, theme::value_style()),
+            Span::styled("  Network ", theme::label_style()),
+            Span::styled("per-process usage not available", theme::label_style()),
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(summary_lines), chunks[0]);
+
+    // Command line
+    let cmd = inspected
+        .map(|i| i.cmd.as_str())
+        .filter(|c| !c.is_empty())
+        .unwrap_or("-");
+    let cmd_line = Line::from(vec![
+        Span::styled(" Cmd ", theme::label_style()),
+        Span::styled(cmd, theme::value_style()),
+    ]);
+    frame.render_widget(
+        Paragraph::new(cmd_line).wrap(Wrap { trim: false }),
+        chunks[1],
+    );
+
+    // CPU / memory history (collected while the inspector is open)
+    if let Some(i) = inspected {
+        let width = sparkline_panel::history_len(chunks[2].width);
+        let cpu_data = i.cpu_history.as_u64_vec(width);
+        let cpu_max = i.cpu_history.max().max(100.0) as u64;
+        sparkline_panel::render(
+            frame,
+            chunks[2],
+            opts,
+            "CPU",
+            &cpu_data,
+            Some(cpu_max),
+            theme::BLUE,
+            &proc
+                .map(|p| format_percent(p.cpu_usage))
+                .unwrap_or_default(),
+        );
+
+        let mem_data = i.memory_history.as_u64_vec(width);
+        let mem_max = i.memory_history.max() as u64;
+        sparkline_panel::render(
+            frame,
+            chunks[3],
+            opts,
+            "Memory",
+            &mem_data,
+            Some(mem_max.max(1)),
+            theme::GREEN,
+            &proc.map(|p| format_bytes(p.memory)).unwrap_or_default(),
+        );
+    }
+
+    // Recent log lines from this process
+    let log_height = chunks[4].height.saturating_sub(2) as usize;
+    let mut log_lines: Vec<Line> = log_stream
+        .entries
+        .iter()
+        .rev()
+        .filter(|e| e.process.eq_ignore_ascii_case(name))
+        .take(log_height)
+        .map(|e| {
+            Line::from(vec![
+                Span::styled(&e.timestamp, theme::label_style()),
+                Span::raw(" "),
+                Span::styled(e.message.lines().next().unwrap_or(""), theme::value_style()),
+            ])
+        })
+        .collect();
+    log_lines.reverse();
+    if log_lines.is_empty() {
+        log_lines.push(Line::styled(" No log lines seen", theme::label_style()));
+    }
+    let log_block = Block::default()
+        .title(Line::styled(" Logs ", theme::title_style()))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style());
+    frame.render_widget(Paragraph::new(log_lines).block(log_block), chunks[4]);
+}
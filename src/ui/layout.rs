@@ -6,13 +6,15 @@ pub struct AppLayout {
     pub footer: Rect,
 }
 
-pub fn compute_layout(area: Rect) -> AppLayout {
+/// With `show_header` off the header shrinks to a one-line status; with
+/// `show_footer` off the footer takes no space.
+pub fn compute_layout(area: Rect, show_header: bool, show_footer: bool) -> AppLayout {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(2), // header + tab bar
-            Constraint::Min(10),   // body
-            Constraint::Length(1), // footer
+            Constraint::Length(if show_header { 2 } else { 1 }), // header + tab bar
+            Constraint::Min(10),                                 // body
+            Constraint::Length(u16::from(show_footer)),          // footer
         ])
         .split(area);
 
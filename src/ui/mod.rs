@@ -1,9 +1,11 @@
 pub mod footer;
 pub mod header;
 pub mod help;
+pub mod inspector;
 pub mod layout;
 pub mod tabs;
 pub mod theme;
+pub mod toast;
 pub mod widgets;
 
 use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
@@ -13,7 +15,32 @@ use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table};
 use ratatui::Frame;
 
 use crate::app::{AiInputMode, App};
+use crate::metrics::ai::ChatStatus;
+use crate::metrics::process::Signal;
+use crate::util::truncate_str;
 use tabs::Tab;
+use theme::{Palette, PaletteKind};
+
+/// Smallest terminal the tab layouts can render into without clipping.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 10;
+
+/// Display toggles the tabs render with, owned by `App` so its keys can
+/// flip them between frames.
+#[derive(Clone, Copy)]
+pub struct RenderOptions {
+    /// Braille sparklines, two samples per cell, instead of blocks.
+    pub hi_res: bool,
+    /// Downsample sparklines with the opposite of each panel's default mode.
+    pub swap_downsample: bool,
+    pub palette: PaletteKind,
+}
+
+impl RenderOptions {
+    pub fn palette(&self) -> &'static Palette {
+        self.palette.colors()
+    }
+}
 
 pub fn render(frame: &mut Frame, app: &App) {
     let area = frame.area();
@@ -24,54 +51,123 @@ pub fn render(frame: &mut Frame, app: &App) {
         area,
     );
 
-    let app_layout = layout::compute_layout(area);
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        render_too_small(frame, area);
+        return;
+    }
+
+    let app_layout = layout::compute_layout(area, app.show_chrome, app.footer_visible());
 
     // Header
-    let hostname = app.hostname.as_str();
-    let uptime = app.metrics.uptime();
-    let load_avg = app.metrics.cpu.load_avg;
-    header::render(
-        frame,
-        app_layout.header,
-        app.current_tab,
-        hostname,
-        uptime,
-        load_avg,
-    );
+    if app.show_chrome {
+        render_header(frame, app_layout.header, app);
+    } else {
+        header::render_status(frame, app_layout.header, app.current_tab);
+    }
 
     // Body - dispatch to current tab
     match app.current_tab {
-        Tab::Dashboard => tabs::dashboard::render(frame, app_layout.body, &app.metrics),
-        Tab::Cpu => tabs::cpu_detail::render(frame, app_layout.body, &app.metrics),
-        Tab::Memory => tabs::memory_detail::render(frame, app_layout.body, &app.metrics),
-        Tab::Disk => tabs::disk_detail::render(frame, app_layout.body, &app.metrics),
-        Tab::Network => tabs::network_detail::render(frame, app_layout.body, &app.metrics),
+        Tab::Dashboard => tabs::dashboard::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.dashboard_density,
+            app.temp_unit,
+            app.render_options,
+        ),
+        Tab::Cpu => tabs::cpu_detail::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.line_chart_interval(Tab::Cpu),
+            app.zoomed,
+            app.render_options,
+        ),
+        Tab::Memory => tabs::memory_detail::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.line_chart_interval(Tab::Memory),
+            app.zoomed,
+            app.render_options,
+        ),
+        Tab::Disk => tabs::disk_detail::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.zoomed,
+            app.render_options,
+        ),
+        Tab::Network => tabs::network_detail::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.line_chart_interval(Tab::Network),
+            app.zoomed,
+            app.render_options,
+        ),
         Tab::Processes => tabs::processes::render(
             frame,
             app_layout.body,
             &app.metrics,
             app.scroll_offset,
             app.process_selected,
+            app.col_offset,
+            app.followed_pid.is_some(),
+            app.zoomed,
+            app.render_options,
+        ),
+        Tab::Logs => tabs::logs::render(
+            frame,
+            app_layout.body,
+            &app.log_stream,
+            app.scroll_offset,
+            app.log_selected,
+            app.log_wrap,
+            app.log_hscroll,
+        ),
+        Tab::Gpu => tabs::gpu_detail::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.zoomed,
+            app.render_options,
+        ),
+        Tab::Ai => tabs::ai_detail::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.ai_chat_scroll,
+            app.render_options,
+        ),
+        Tab::Temperatures => tabs::temperatures::render(
+            frame,
+            app_layout.body,
+            &app.metrics,
+            app.temp_unit,
+            app.zoomed,
+            app.render_options,
         ),
-        Tab::Logs => tabs::logs::render(frame, app_layout.body, &app.log_stream, app.scroll_offset),
-        Tab::Gpu => tabs::gpu_detail::render(frame, app_layout.body, &app.metrics),
-        Tab::Ai => {
-            tabs::ai_detail::render(frame, app_layout.body, &app.metrics, app.ai_chat_scroll)
-        }
-        Tab::Temperatures => tabs::temperatures::render(frame, app_layout.body, &app.metrics),
     }
 
     // Footer
-    footer::render(
-        frame,
-        app_layout.footer,
-        app.current_tab,
-        app.filter_mode,
-        &app.filter_buffer,
-        app.refresh_rate,
-        &app.metrics.ai,
-        app.copy_feedback,
-    );
+    if app.footer_visible() {
+        footer::render(
+            frame,
+            app_layout.footer,
+            app.current_tab,
+            app.filter_mode,
+            app.search_mode,
+            !app.metrics.processes.search_query.is_empty(),
+            app.goto_mode,
+            &app.filter_buffer,
+            app.effective_refresh_rate(),
+            app.is_idle(),
+            &app.metrics.ai,
+        );
+    }
+
+    toast::render(frame, app_layout.body, &app.toasts);
 
     // Kill confirmation overlay
     if let Some((pid, ref name)) = app.confirm_kill {
@@ -95,6 +191,111 @@ pub fn render(frame: &mut Frame, app: &App) {
         frame.render_widget(p, popup);
     }
 
+    // Renice confirmation overlay
+    if let Some((pid, ref name, nice)) = app.confirm_renice {
+        let popup = centered_rect(50, 5, area);
+        frame.render_widget(Clear, popup);
+        let text = Line::from(vec![
+            Span::styled("Renice ", Style::default().fg(theme::YELLOW)),
+            Span::styled(format!("{name} (PID {pid})"), theme::value_style()),
+            Span::styled(format!(" to {nice}? "), Style::default().fg(theme::YELLOW)),
+            Span::styled("[y]es / [any] cancel", theme::label_style()),
+        ]);
+        let block = Block::default()
+            .title(Line::styled(
+                " Confirm Renice ",
+                Style::default().fg(theme::YELLOW),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme::YELLOW))
+            .style(Style::default().bg(theme::BASE));
+        let p = Paragraph::new(text).block(block);
+        frame.render_widget(p, popup);
+    }
+
+    // Signal menu overlay
+    if let Some((pid, ref name, selected)) = app.signal_menu {
+        let popup = centered_rect(50, Signal::ALL.len() as u16 + 2, area);
+        frame.render_widget(Clear, popup);
+        let rows: Vec<Row> = Signal::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, signal)| {
+                let row = Row::new(vec![
+                    Cell::from(Span::styled(signal.name(), theme::value_style())),
+                    Cell::from(Span::styled(signal.description(), theme::label_style())),
+                ]);
+                if i == selected {
+                    row.style(theme::highlight_style())
+                } else {
+                    row
+                }
+            })
+            .collect();
+        let block = Block::default()
+            .title(Line::styled(
+                format!(" Signal {name} (PID {pid}) — Enter send, Esc cancel "),
+                theme::title_style(),
+            ))
+            .borders(Borders::ALL)
+            .border_style(theme::border_style())
+            .style(Style::default().bg(theme::BASE));
+        let widths = [Constraint::Length(9), Constraint::Min(10)];
+        frame.render_widget(Table::new(rows, widths).block(block), popup);
+    }
+
+    // Signal confirmation overlay
+    if let Some((pid, ref name, signal)) = app.confirm_signal {
+        let popup = centered_rect(50, 5, area);
+        frame.render_widget(Clear, popup);
+        let text = Line::from(vec![
+            Span::styled(
+                format!("Send {} to ", signal.name()),
+                Style::default().fg(theme::RED),
+            ),
+            Span::styled(format!("{name} (PID {pid})"), theme::value_style()),
+            Span::styled("? ", Style::default().fg(theme::RED)),
+            Span::styled("[y]es / [any] cancel", theme::label_style()),
+        ]);
+        let block = Block::default()
+            .title(Line::styled(
+                " Confirm Signal ",
+                Style::default().fg(theme::RED),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme::RED))
+            .style(Style::default().bg(theme::BASE));
+        let p = Paragraph::new(text).block(block);
+        frame.render_widget(p, popup);
+    }
+
+    // Quit confirmation overlay
+    if app.confirm_quit {
+        let popup = centered_rect(50, 5, area);
+        frame.render_widget(Clear, popup);
+        let mut text = vec![Span::styled(
+            "Quit peakmon? ",
+            Style::default().fg(theme::RED),
+        )];
+        if app.metrics.ai.chat_status == ChatStatus::Generating {
+            text.push(Span::styled(
+                "(chat still generating) ",
+                theme::value_style(),
+            ));
+        }
+        text.push(Span::styled("[y]es / [any] cancel", theme::label_style()));
+        let block = Block::default()
+            .title(Line::styled(
+                " Confirm Quit ",
+                Style::default().fg(theme::RED),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme::RED))
+            .style(Style::default().bg(theme::BASE));
+        let p = Paragraph::new(Line::from(text)).block(block);
+        frame.render_widget(p, popup);
+    }
+
     // AI delete confirmation overlay
     if let Some(ref model_name) = app.ai_confirm_delete {
         let popup = centered_rect(50, 5, area);
@@ -177,12 +378,48 @@ pub fn render(frame: &mut Frame, app: &App) {
         render_search_overlay(frame, area, app);
     }
 
+    // Process inspector overlay
+    if let Some(pid) = app.inspect_pid {
+        inspector::render(
+            frame,
+            area,
+            pid,
+            &app.metrics,
+            &app.log_stream,
+            app.render_options,
+        );
+    }
+
     // Help overlay
     if app.show_help {
-        help::render(frame, area);
+        help::render(frame, area, app.current_tab, app.help_scroll);
     }
 }
 
+fn render_too_small(frame: &mut Frame, area: Rect) {
+    let lines = vec![
+        Line::styled("terminal too small", Style::default().fg(theme::RED)),
+        Line::styled(
+            format!("need \u{2265} {MIN_WIDTH}x{MIN_HEIGHT}"),
+            theme::label_style(),
+        ),
+        Line::styled(
+            format!("have {}x{}", area.width, area.height),
+            theme::label_style(),
+        ),
+    ];
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let msg_area = Rect {
+        y: area.y + top,
+        height: area.height.saturating_sub(top),
+        ..area
+    };
+    frame.render_widget(
+        Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center),
+        msg_area,
+    );
+}
+
 fn render_search_overlay(frame: &mut Frame, area: Rect, app: &App) {
     let ai = &app.metrics.ai;
     let popup = centered_rect(80, 22, area);
@@ -190,7 +427,7 @@ fn render_search_overlay(frame: &mut Frame, area: Rect, app: &App) {
 
     let block = Block::default()
         .title(Line::styled(
-            " Search Results — Enter to pull, S new search, Esc to close ",
+            " Search Results — Enter to pull, y copy command, O open page, S new search, Esc to close ",
             theme::title_style(),
         ))
         .borders(Borders::ALL)
@@ -232,11 +469,7 @@ fn render_search_overlay(frame: &mut Frame, area: Rect, app: &App) {
             let sizes = result.sizes.join(", ");
 
             // Truncate description to fit
-            let desc = if result.description.len() > 40 {
-                format!("{}...", &result.description[..37])
-            } else {
-                result.description.clone()
-            };
+            let desc = truncate_str(&result.description, 40);
 
             Row::new(vec![
                 Cell::from(Span::styled(&*result.name, theme::value_style())),
@@ -263,6 +496,30 @@ fn render_search_overlay(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(table, popup);
 }
 
+fn render_header(frame: &mut Frame, area: Rect, app: &App) {
+    let hostname = app.hostname.as_str();
+    let uptime = app.metrics.uptime();
+    let awake = app.metrics.awake_uptime();
+    let load_avg = app.metrics.cpu.load_avg;
+    let mut alerted_tabs = app.alerts.flagged_tabs();
+    if app.log_stream.error_pending {
+        alerted_tabs.push(Tab::Logs);
+    }
+    header::render(
+        frame,
+        area,
+        app.current_tab,
+        hostname,
+        uptime,
+        awake,
+        load_avg,
+        &alerted_tabs,
+        app.metrics.data_age(app.current_tab),
+        app.metrics.is_stale(app.current_tab),
+        app.metrics.processes.own_usage,
+    );
+}
+
 fn centered_rect(width_pct: u16, height: u16, area: Rect) -> Rect {
     let vertical = Layout::default()
         .direction(Direction::Vertical)
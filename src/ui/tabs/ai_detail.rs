@@ -5,17 +5,29 @@ use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Frame;
 
 use crate::metrics::ai::{AiMetrics, ChatStatus, PullStatus};
-use crate::metrics::gpu::GpuMetrics;
+use crate::metrics::ane::AneMetrics;
+use crate::metrics::gpu::GpuDevice;
 use crate::metrics::MetricsCollector;
 use crate::ui::theme;
-use crate::ui::widgets::sparkline_panel;
-use crate::util::{format_bytes, format_percent};
+use crate::ui::widgets::{scrollbar, sparkline_panel};
+use crate::ui::RenderOptions;
+use crate::util::{format_bytes, format_percent, format_rate, format_uptime};
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector, chat_scroll: usize) {
+/// Benchmark leaderboard rows shown at most.
+const MAX_BENCHMARK_ROWS: usize = 5;
+
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    chat_scroll: usize,
+    opts: RenderOptions,
+) {
     let ai = &metrics.ai;
 
     let has_chat = !ai.chat_messages.is_empty();
     let has_perf = ai.chat_metrics.is_some();
+    let bench_count = ai.benchmarks.len().min(MAX_BENCHMARK_ROWS) as u16;
 
     // Dynamic layout: allocate space based on what content exists
     let perf_height = if has_perf { 3 } else { 0 };
@@ -31,6 +43,9 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector, chat_sc
     if has_perf {
         constraints.push(Constraint::Length(perf_height)); // Performance bar
     }
+    if bench_count > 0 {
+        constraints.push(Constraint::Length(bench_count + 3)); // Benchmark leaderboard
+    }
     constraints.push(Constraint::Length(3)); // AI Resource Usage sparkline
 
     let chunks = Layout::default()
@@ -49,7 +64,29 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector, chat_sc
         render_performance(frame, chunks[idx], ai);
         idx += 1;
     }
-    render_resource_usage(frame, chunks[idx], ai, &metrics.gpu, area.width);
+    if bench_count > 0 {
+        render_benchmarks(frame, chunks[idx], ai);
+        idx += 1;
+    }
+    // The Neural Engine runs many models instead of the GPU, so show both
+    let resources = chunks[idx];
+    if metrics.ane.available {
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(resources);
+        render_resource_usage(
+            frame,
+            halves[0],
+            ai,
+            metrics.gpu.device(),
+            halves[0].width,
+            opts,
+        );
+        render_ane(frame, halves[1], &metrics.ane, opts);
+    } else {
+        render_resource_usage(frame, resources, ai, metrics.gpu.device(), area.width, opts);
+    }
 }
 
 fn render_services(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
@@ -141,6 +178,7 @@ fn render_models(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
         Cell::from(Span::styled("VRAM", theme::title_style())),
         Cell::from(Span::styled("Status", theme::title_style())),
         Cell::from(Span::styled("tok/s", theme::title_style())),
+        Cell::from(Span::styled("avg / peak", theme::title_style())),
     ])
     .height(1);
 
@@ -158,10 +196,10 @@ fn render_models(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
                 .model_vram(&model.name)
                 .unwrap_or_else(|| "-".to_string());
             let status = ai.model_status(&model.name);
-            let status_color = if status == "Loaded" {
-                theme::GREEN
-            } else {
-                theme::SUBTEXT
+            let status_color = match status {
+                "Pinned" => theme::TEAL,
+                "Loaded" => theme::GREEN,
+                _ => theme::SUBTEXT,
             };
 
             let tps = ai
@@ -169,6 +207,11 @@ fn render_models(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
                 .get(&model.name)
                 .map(|t| format!("{t:.1}"))
                 .unwrap_or_else(|| "-".to_string());
+            let tps_stats = ai
+                .tps_stats
+                .get(&model.name)
+                .map(|s| format!("{:.1} / {:.1}", s.avg(), s.max))
+                .unwrap_or_else(|| "-".to_string());
 
             let style = if i == ai.model_selected {
                 theme::highlight_style()
@@ -183,6 +226,7 @@ fn render_models(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
                 Cell::from(Span::styled(vram, theme::label_style())),
                 Cell::from(Span::styled(status, Style::default().fg(status_color))),
                 Cell::from(Span::styled(tps, Style::default().fg(theme::TEAL))),
+                Cell::from(Span::styled(tps_stats, theme::label_style())),
             ])
             .style(style)
         })
@@ -191,12 +235,13 @@ fn render_models(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
     let table = Table::new(
         rows,
         [
-            Constraint::Percentage(30),
-            Constraint::Percentage(12),
-            Constraint::Percentage(12),
+            Constraint::Percentage(26),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
             Constraint::Percentage(12),
-            Constraint::Percentage(16),
-            Constraint::Percentage(18),
+            Constraint::Percentage(10),
+            Constraint::Percentage(22),
         ],
     )
     .header(header)
@@ -211,14 +256,26 @@ fn render_models(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
 }
 
 fn render_pull_progress(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
-    if let Some(PullStatus::Progress { status, percent }) = &ai.pull_status {
+    if let Some(PullStatus::Progress {
+        status,
+        percent,
+        rate,
+        eta_secs,
+    }) = &ai.pull_status
+    {
         let model_name = ai.pull_model_name.as_deref().unwrap_or("model");
         let pct = percent.unwrap_or(0.0);
-        let pct_display = if percent.is_some() {
+        let mut pct_display = if percent.is_some() {
             format!(" {pct:.0}%")
         } else {
             String::new()
         };
+        if let Some(rate) = rate {
+            pct_display.push_str(&format!(" · {}", format_rate(*rate)));
+        }
+        if let Some(eta) = eta_secs {
+            pct_display.push_str(&format!(" · ETA {}", format_uptime(*eta)));
+        }
 
         let title = format!(" Pulling {model_name}{pct_display} — {status} ");
 
@@ -260,6 +317,7 @@ fn render_pull_progress(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
 
 fn render_chat(frame: &mut Frame, area: Rect, ai: &AiMetrics, chat_scroll: usize) {
     let status_indicator = match &ai.chat_status {
+        ChatStatus::Generating if ai.benchmarking => " [benchmarking...] ",
         ChatStatus::Generating => " [generating...] ",
         ChatStatus::Error(e) => {
             // We'll show error in title - truncate if needed
@@ -269,10 +327,16 @@ fn render_chat(frame: &mut Frame, area: Rect, ai: &AiMetrics, chat_scroll: usize
         _ => "",
     };
 
+    let dropped = ai.dropped_messages();
+    let context_info = if dropped > 0 {
+        format!(" [{dropped} older out of context] ")
+    } else {
+        String::new()
+    };
     let title_extra = if let Some(ref model) = ai.chat_model {
-        format!(" Chat — {model}{status_indicator}")
+        format!(" Chat — {model}{status_indicator}{context_info}")
     } else {
-        format!(" Chat{status_indicator}")
+        format!(" Chat{status_indicator}{context_info}")
     };
 
     let block = Block::default()
@@ -388,6 +452,7 @@ fn render_chat(frame: &mut Frame, area: Rect, ai: &AiMetrics, chat_scroll: usize
         .scroll((scroll as u16, 0));
 
     frame.render_widget(paragraph, area);
+    scrollbar::render(frame, area, scroll, visible_height, total_lines);
 }
 
 fn wrap_text(text: &str, width: usize) -> Vec<String> {
@@ -475,26 +540,98 @@ fn render_performance(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
     }
 }
 
+/// Benchmark results per model, fastest first.
+fn render_benchmarks(frame: &mut Frame, area: Rect, ai: &AiMetrics) {
+    let block = Block::default()
+        .title(Line::styled(" Benchmarks ", theme::title_style()))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+
+    let header = Row::new(vec![
+        Cell::from(Span::styled("Model", theme::title_style())),
+        Cell::from(Span::styled("tok/s", theme::title_style())),
+        Cell::from(Span::styled("TTFT", theme::title_style())),
+        Cell::from(Span::styled("Total", theme::title_style())),
+        Cell::from(Span::styled("Tokens", theme::title_style())),
+    ])
+    .height(1);
+
+    let rows: Vec<Row> = ai
+        .benchmark_ranking()
+        .into_iter()
+        .take(MAX_BENCHMARK_ROWS)
+        .map(|(model, result)| {
+            Row::new(vec![
+                Cell::from(Span::styled(model.as_str(), theme::value_style())),
+                Cell::from(Span::styled(
+                    format!("{:.1}", result.tokens_per_sec),
+                    Style::default().fg(theme::TEAL),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:.0}ms", result.ttft_ms),
+                    Style::default().fg(theme::PEACH),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:.1}s", result.total_duration_ms / 1000.0),
+                    theme::label_style(),
+                )),
+                Cell::from(Span::styled(
+                    result.gen_tokens.to_string(),
+                    theme::label_style(),
+                )),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(40),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+        ],
+    )
+    .header(header)
+    .block(block);
+    frame.render_widget(table, area);
+}
+
 fn render_resource_usage(
     frame: &mut Frame,
     area: Rect,
     ai: &AiMetrics,
-    gpu: &GpuMetrics,
+    gpu: Option<&GpuDevice>,
     width: u16,
+    opts: RenderOptions,
 ) {
+    let gpu_summary = match gpu {
+        Some(gpu) => format!(
+            "GPU: {}  GPU Mem: {} / {}",
+            format_percent(gpu.device_utilization),
+            format_bytes(gpu.in_use_memory),
+            format_bytes(gpu.alloc_memory),
+        ),
+        None => "GPU: n/a".to_string(),
+    };
     let annotation = format!(
-        "GPU: {}  GPU Mem: {} / {}  |  CPU: {}  Proc Mem: {}",
-        format_percent(gpu.device_utilization),
-        format_bytes(gpu.in_use_memory),
-        format_bytes(gpu.alloc_memory),
+        "{gpu_summary}  |  CPU: {}  Proc Mem: {}",
         format_percent(ai.aggregate_cpu),
         format_bytes(ai.aggregate_memory)
     );
 
-    let data = gpu.utilization_history.as_u64_vec(width as usize);
+    let data = gpu
+        .map(|g| {
+            g.utilization_history
+                .as_u64_vec(sparkline_panel::history_len(width))
+        })
+        .unwrap_or_default();
     sparkline_panel::render(
         frame,
         area,
+        opts,
         "AI Resources",
         &data,
         Some(100),
@@ -502,3 +639,24 @@ fn render_resource_usage(
         &annotation,
     );
 }
+
+/// Neural Engine power draw, scaled to the session peak.
+fn render_ane(frame: &mut Frame, area: Rect, ane: &AneMetrics, opts: RenderOptions) {
+    let annotation = match ane.ane_power_mw {
+        Some(mw) => format!("{mw:.0} mW  peak {:.0} mW", ane.peak_power_mw),
+        None => "sampling…".to_string(),
+    };
+    let data = ane
+        .power_history
+        .as_u64_vec(sparkline_panel::history_len(area.width));
+    sparkline_panel::render(
+        frame,
+        area,
+        opts,
+        "ANE Power",
+        &data,
+        Some((ane.peak_power_mw as u64).max(1)),
+        theme::TEAL,
+        &annotation,
+    );
+}
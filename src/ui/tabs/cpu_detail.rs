@@ -1,49 +1,85 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
-use ratatui::text::Line;
-use ratatui::widgets::{BarChart, Block, Borders};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph};
 use ratatui::Frame;
+use std::time::Duration;
 
+use crate::metrics::history::Downsample;
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
+use crate::ui::widgets::line_chart::{self, Series};
 use crate::ui::widgets::sparkline_panel;
+use crate::ui::RenderOptions;
 use crate::util::format_percent;
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
+/// `line_chart` is the sample interval when the tab shows a line chart of
+/// the whole history instead of the sparkline.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    line_chart: Option<Duration>,
+    zoomed: bool,
+    opts: RenderOptions,
+) {
+    let history_height = if line_chart.is_some() { 14 } else { 7 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(7), // Aggregate sparkline
-            Constraint::Min(8),    // Per-core bar chart
-        ])
+        .constraints(zoom_constraints(
+            [
+                Constraint::Length(history_height), // Aggregate history
+                Constraint::Length(3),              // User/system/idle breakdown
+                Constraint::Min(8),                 // Per-core bar chart
+            ],
+            &[0],
+            zoomed,
+        ))
         .split(area);
 
-    // Aggregate CPU sparkline
-    let cpu_data = metrics
-        .cpu
-        .aggregate_history
-        .as_u64_vec(area.width as usize);
-    let cpu_label = format_percent(metrics.cpu.aggregate_usage);
-    sparkline_panel::render(
-        frame,
-        chunks[0],
-        "CPU (aggregate)",
-        &cpu_data,
-        Some(100),
-        theme::BLUE,
-        &cpu_label,
-    );
+    // Aggregate CPU history
+    let cpu_label = metrics.cpu.usage_label();
+    let title = if metrics.cpu.smooth {
+        "CPU (aggregate, smoothed)"
+    } else {
+        "CPU (aggregate)"
+    };
+    if let Some(interval) = line_chart {
+        let series = [Series {
+            name: "CPU",
+            history: metrics.cpu.plotted_history(),
+            color: theme::BLUE,
+        }];
+        let title = format!("{title} {cpu_label}");
+        line_chart::render(frame, chunks[0], &title, &series, 100.0, interval, |v| {
+            format!("{v:.0}%")
+        });
+    } else {
+        sparkline_panel::render_history(
+            frame,
+            chunks[0],
+            opts,
+            title,
+            metrics.cpu.plotted_history(),
+            Downsample::Average,
+            Some(100),
+            theme::BLUE,
+            &cpu_label,
+        );
+    }
+
+    render_breakdown(frame, chunks[1], metrics);
 
-    // Per-core bar chart
-    let core_labels: Vec<String> = (0..metrics.cpu.core_count)
-        .map(|i| format!("C{i}"))
-        .collect();
-    let bar_data: Vec<(&str, u64)> = core_labels
-        .iter()
-        .enumerate()
-        .map(|(i, label)| {
+    // Per-core bar chart, each bar colored by its own load like the gauges
+    let bars: Vec<Bar> = (0..metrics.cpu.core_count)
+        .map(|i| {
             let usage = metrics.cpu.per_core_usage.get(i).copied().unwrap_or(0.0);
-            (label.as_str(), usage as u64)
+            Bar::default()
+                .label(Line::from(format!("C{i}")))
+                .value(usage as u64)
+                .style(theme::gauge_style(usage, opts.palette()))
+                .value_style(Style::default().fg(theme::TEXT))
         })
         .collect();
 
@@ -66,12 +102,65 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
 
     let barchart = BarChart::default()
         .block(bar_block)
-        .data(&bar_data)
+        .data(BarGroup::default().bars(&bars))
         .bar_width(bar_width)
         .bar_gap(1)
-        .bar_style(Style::default().fg(theme::BLUE))
-        .value_style(Style::default().fg(theme::TEXT))
         .max(100);
 
-    frame.render_widget(barchart, chunks[1]);
+    frame.render_widget(barchart, chunks[2]);
+}
+
+/// Stacked bar of user/system/nice/idle time with a legend underneath.
+fn render_breakdown(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
+    let b = &metrics.cpu.breakdown;
+    let segments = [
+        ("user", b.user, theme::BLUE),
+        ("system", b.system, theme::RED),
+        ("nice", b.nice, theme::YELLOW),
+        ("idle", b.idle, theme::SURFACE1),
+    ];
+
+    let mut legend = vec![Span::raw(" ")];
+    for (label, pct, color) in segments {
+        legend.push(Span::styled(format!("{label} "), theme::label_style()));
+        legend.push(Span::styled(
+            format!("{}  ", format_percent(pct)),
+            Style::default().fg(color),
+        ));
+    }
+
+    let mut title = vec![Span::styled(" CPU Breakdown ", theme::title_style())];
+    if metrics.cpu.is_throttled() {
+        let limit = metrics.cpu.speed_limit.unwrap_or(0);
+        title.push(Span::styled(
+            format!(" THROTTLED ({limit}% speed) "),
+            Style::default().fg(theme::BASE).bg(theme::RED),
+        ));
+    }
+
+    let block = Block::default()
+        .title(Line::from(title))
+        .title_bottom(Line::from(legend))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+    let width = block.inner(area).width as usize;
+
+    // Round each boundary rather than each segment so the bar always fills
+    let mut bar = Vec::new();
+    let mut filled = 0;
+    let mut cumulative = 0.0;
+    for (_, pct, color) in segments {
+        cumulative += pct;
+        let end = ((cumulative / 100.0 * width as f64).round() as usize).min(width);
+        if end > filled {
+            bar.push(Span::styled(
+                "\u{2588}".repeat(end - filled),
+                Style::default().fg(color),
+            ));
+            filled = end;
+        }
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(bar)).block(block), area);
 }
@@ -1,53 +1,205 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::Style;
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Row, Table};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
 use ratatui::Frame;
 
+use crate::metrics::temperature::TempUnit;
 use crate::metrics::MetricsCollector;
 use crate::ui::theme;
 use crate::ui::widgets::{metric_gauge, sparkline_panel};
+use crate::ui::RenderOptions;
 use crate::util::{format_bytes, format_percent, format_rate};
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
-    let has_battery = metrics.battery.available;
+/// Minimum body height for the stacked (normal) layout before auto-compacting.
+const NORMAL_MIN_HEIGHT: u16 = 23;
 
-    let mut constraints = vec![
-        Constraint::Length(5), // CPU sparkline
-        Constraint::Length(3), // Memory gauge
-        Constraint::Length(3), // Swap gauge
-    ];
+/// Height of the GPU sparkline row, added to the minimum when it's shown.
+const GPU_ROW_HEIGHT: u16 = 5;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DashboardDensity {
+    Normal,
+    Compact,
+}
+
+impl DashboardDensity {
+    pub fn toggle(self) -> Self {
+        match self {
+            DashboardDensity::Normal => DashboardDensity::Compact,
+            DashboardDensity::Compact => DashboardDensity::Normal,
+        }
+    }
+}
+
+/// Row constraints for the gauge stack. In normal density this is the whole
+/// dashboard; in compact density it's only the left column.
+fn layout_constraints(
+    density: DashboardDensity,
+    has_gpu: bool,
+    has_battery: bool,
+) -> Vec<Constraint> {
+    let mut constraints = match density {
+        DashboardDensity::Normal => vec![Constraint::Length(5)], // CPU sparkline
+        DashboardDensity::Compact => vec![Constraint::Min(3)],   // CPU sparkline
+    };
+    if has_gpu {
+        constraints.push(Constraint::Length(GPU_ROW_HEIGHT)); // GPU sparkline
+    }
+    constraints.push(Constraint::Length(3)); // Memory gauge
+    constraints.push(Constraint::Length(3)); // Swap gauge
     if has_battery {
         constraints.push(Constraint::Length(3)); // Battery gauge
     }
-    constraints.push(Constraint::Min(6)); // Top processes table
-    constraints.push(Constraint::Length(3)); // Network summary
+    if density == DashboardDensity::Normal {
+        constraints.push(Constraint::Min(6)); // Top processes table
+        constraints.push(Constraint::Length(3)); // Network summary
+    }
+    constraints
+}
+
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    density: DashboardDensity,
+    temp_unit: TempUnit,
+    opts: RenderOptions,
+) {
+    let has_battery = metrics.battery.available;
+    let has_gpu = metrics.gpu.primary().is_some();
+
+    // Nearly-full volumes get a warning line above everything else
+    let full: Vec<String> = metrics
+        .disk
+        .nearly_full()
+        .map(|d| format!("{} {}", d.mount_point, format_percent(d.used_percent)))
+        .collect();
+    let area = if full.is_empty() {
+        area
+    } else {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        let warning = Line::styled(
+            format!(" \u{26a0} Disk nearly full: {}", full.join(", ")),
+            Style::default().fg(theme::RED).add_modifier(Modifier::BOLD),
+        );
+        frame.render_widget(Paragraph::new(warning), rows[0]);
+        rows[1]
+    };
+
+    // Auto-compact when the stacked layout can't fit
+    let min_height = NORMAL_MIN_HEIGHT + if has_gpu { GPU_ROW_HEIGHT } else { 0 };
+    let density = if area.height < min_height {
+        DashboardDensity::Compact
+    } else {
+        density
+    };
+
+    let (gauge_area, proc_area) = match density {
+        DashboardDensity::Normal => (area, None),
+        DashboardDensity::Compact => {
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+                .split(area);
+            (cols[0], Some(cols[1]))
+        }
+    };
 
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(constraints)
-        .split(area);
+        .constraints(layout_constraints(density, has_gpu, has_battery))
+        .split(gauge_area);
 
     let mut idx = 0;
 
-    // CPU sparkline
+    render_cpu(frame, main_chunks[idx], metrics, area.width, opts);
+    idx += 1;
+    if has_gpu {
+        render_gpu(frame, main_chunks[idx], metrics, area.width, opts);
+        idx += 1;
+    }
+    render_memory(frame, main_chunks[idx], metrics, opts);
+    idx += 1;
+    render_swap(frame, main_chunks[idx], metrics, opts);
+    idx += 1;
+
+    if has_battery {
+        render_battery(frame, main_chunks[idx], metrics, temp_unit, opts);
+        idx += 1;
+    }
+
+    match proc_area {
+        Some(proc_area) => {
+            // Network summary moves into the process panel's bottom border
+            render_processes(frame, proc_area, metrics, Some(network_summary(metrics)));
+        }
+        None => {
+            render_processes(frame, main_chunks[idx], metrics, None);
+            idx += 1;
+            render_network(frame, main_chunks[idx], metrics);
+        }
+    }
+}
+
+fn render_cpu(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    width: u16,
+    opts: RenderOptions,
+) {
     let cpu_data = metrics
         .cpu
-        .aggregate_history
-        .as_u64_vec(area.width as usize);
-    let cpu_label = format_percent(metrics.cpu.aggregate_usage);
+        .plotted_history()
+        .as_u64_vec(sparkline_panel::history_len(width));
+    let cpu_label = metrics.cpu.usage_label();
+    let title = if metrics.cpu.smooth {
+        "CPU (smoothed)"
+    } else {
+        "CPU"
+    };
     sparkline_panel::render(
         frame,
-        main_chunks[idx],
-        "CPU",
+        area,
+        opts,
+        title,
         &cpu_data,
         Some(100),
         theme::BLUE,
         &cpu_label,
     );
-    idx += 1;
+}
 
-    // Memory gauge
+fn render_gpu(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    width: u16,
+    opts: RenderOptions,
+) {
+    let Some(gpu) = metrics.gpu.primary() else {
+        return;
+    };
+    let gpu_data = gpu
+        .utilization_history
+        .as_u64_vec(sparkline_panel::history_len(width));
+    sparkline_panel::render(
+        frame,
+        area,
+        opts,
+        "GPU",
+        &gpu_data,
+        Some(100),
+        theme::GREEN,
+        &format!("{:.0}%", gpu.device_utilization),
+    );
+}
+
+fn render_memory(frame: &mut Frame, area: Rect, metrics: &MetricsCollector, opts: RenderOptions) {
     let mem_label = format!(
         "{} / {} ({})",
         format_bytes(metrics.memory.used_ram),
@@ -56,48 +208,82 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     );
     metric_gauge::render(
         frame,
-        main_chunks[idx],
+        area,
+        opts.palette(),
         "Memory",
         metrics.memory.ram_percent,
         &mem_label,
     );
-    idx += 1;
+}
 
-    // Swap gauge
+fn render_swap(frame: &mut Frame, area: Rect, metrics: &MetricsCollector, opts: RenderOptions) {
     let swap_label = format!(
         "{} / {} ({})",
         format_bytes(metrics.memory.used_swap),
         format_bytes(metrics.memory.total_swap),
         format_percent(metrics.memory.swap_percent),
     );
-    metric_gauge::render(
+    // Growing swap means active paging, which hurts even at a low percentage
+    let label = if metrics.memory.swapping {
+        Span::styled(
+            format!("\u{26a0} swapping  {swap_label}"),
+            Style::default()
+                .fg(opts.palette().critical)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::raw(swap_label)
+    };
+    metric_gauge::render_label(
         frame,
-        main_chunks[idx],
+        area,
+        opts.palette(),
         "Swap",
         metrics.memory.swap_percent,
-        &swap_label,
+        label,
     );
-    idx += 1;
+}
 
-    // Battery gauge (conditional)
-    if has_battery {
-        let bat = &metrics.battery;
-        let state = if bat.fully_charged {
-            "Charged"
-        } else if bat.is_charging {
-            "Charging"
-        } else {
-            "Discharging"
-        };
-        let bat_label = format!(
-            "{:.0}% \u{25cf} {}  {} cycles  Health: {:.1}%  {:.1}\u{00b0}C  {:.1}W",
-            bat.percent, state, bat.cycle_count, bat.health_percent, bat.temperature, bat.watts,
-        );
-        metric_gauge::render(frame, main_chunks[idx], "Battery", bat.percent, &bat_label);
-        idx += 1;
-    }
+fn render_battery(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    temp_unit: TempUnit,
+    opts: RenderOptions,
+) {
+    let bat = &metrics.battery;
+    let state = if bat.fully_charged {
+        "Charged"
+    } else if bat.is_charging {
+        "Charging"
+    } else {
+        "Discharging"
+    };
+    let bat_label = format!(
+        "{:.0}% \u{25cf} {}  {} cycles  Health: {:.1}%  {}  {:.1}W",
+        bat.percent,
+        state,
+        bat.cycle_count,
+        bat.health_percent,
+        temp_unit.format(bat.temperature),
+        bat.watts,
+    );
+    metric_gauge::render(
+        frame,
+        area,
+        opts.palette(),
+        "Battery",
+        bat.percent,
+        &bat_label,
+    );
+}
 
-    // Top processes
+fn render_processes(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    bottom_note: Option<String>,
+) {
     let procs: Vec<Row> = metrics
         .processes
         .processes
@@ -124,29 +310,38 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
         Constraint::Length(12),
     ];
 
-    let proc_block = Block::default()
+    let mut proc_block = Block::default()
         .title(Line::styled(" Top Processes ", theme::title_style()))
         .borders(Borders::ALL)
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
+    if let Some(note) = bottom_note {
+        proc_block = proc_block.title_bottom(Line::styled(note, theme::value_style()));
+    }
 
     let table = Table::new(procs, &widths).header(header).block(proc_block);
-    frame.render_widget(table, main_chunks[idx]);
-    idx += 1;
+    frame.render_widget(table, area);
+}
 
-    // Network summary
-    let net_info = format!(
+fn network_summary(metrics: &MetricsCollector) -> String {
+    format!(
         " RX: {}  TX: {} ",
         format_rate(metrics.network.total_rx_rate),
         format_rate(metrics.network.total_tx_rate),
-    );
+    )
+}
+
+fn render_network(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     let net_block = Block::default()
         .title(Line::styled(" Network ", theme::title_style()))
         .borders(Borders::ALL)
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
 
-    let net_para = ratatui::widgets::Paragraph::new(Line::styled(net_info, theme::value_style()))
-        .block(net_block);
-    frame.render_widget(net_para, main_chunks[idx]);
+    let net_para = ratatui::widgets::Paragraph::new(Line::styled(
+        network_summary(metrics),
+        theme::value_style(),
+    ))
+    .block(net_block);
+    frame.render_widget(net_para, area);
 }
@@ -1,96 +1,146 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
-use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Cell, Row, Table};
+use ratatui::text::Span;
+use ratatui::widgets::{Cell, Row};
 use ratatui::Frame;
 
+use crate::metrics::disk::DiskSortField;
+use crate::metrics::history::Downsample;
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
+use crate::ui::widgets::sortable_table::{self, SortableColumn};
 use crate::ui::widgets::sparkline_panel;
+use crate::ui::RenderOptions;
 use crate::util::{format_bytes, format_percent, format_rate};
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    zoomed: bool,
+    opts: RenderOptions,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Min(6),    // Volume table
-            Constraint::Length(6), // Read I/O sparkline
-            Constraint::Length(6), // Write I/O sparkline
-        ])
+        .constraints(zoom_constraints(
+            [
+                Constraint::Min(6),    // Volume table
+                Constraint::Length(6), // Read I/O sparkline
+                Constraint::Length(6), // Write I/O sparkline
+            ],
+            &[0],
+            zoomed,
+        ))
         .split(area);
 
-    // Volume table with per-disk I/O
-    let rows: Vec<Row> = metrics
-        .disk
+    // Volume table with per-disk I/O, plus a totals row
+    let disk = &metrics.disk;
+    let mut rows: Vec<Row> = disk
         .disks
         .iter()
         .map(|d| {
-            Row::new(vec![
+            let row = Row::new(vec![
                 Cell::from(Span::raw(d.name.clone())),
                 Cell::from(Span::raw(d.mount_point.clone())),
                 Cell::from(Span::raw(format_bytes(d.total_space))),
                 Cell::from(Span::raw(format_bytes(d.available_space))),
                 Cell::from(Span::styled(
                     format_percent(d.used_percent),
-                    theme::gauge_style(d.used_percent),
+                    theme::gauge_style(d.used_percent, opts.palette()),
                 )),
                 Cell::from(Span::styled(format_rate(d.read_rate), theme::value_style())),
                 Cell::from(Span::styled(
                     format_rate(d.write_rate),
                     theme::value_style(),
                 )),
-            ])
+            ]);
+            if d.nearly_full {
+                row.style(Style::default().fg(theme::BASE).bg(theme::RED))
+            } else {
+                row
+            }
         })
         .collect();
 
-    let header = Row::new(vec![
-        "Name", "Mount", "Total", "Avail", "Used", "Read/s", "Write/s",
-    ])
-    .style(theme::label_style())
-    .height(1);
+    if disk.disks.len() > 1 {
+        let (total, available) = disk.space_totals();
+        let used_pct = if total > 0 {
+            total.saturating_sub(available) as f64 / total as f64 * 100.0
+        } else {
+            0.0
+        };
+        rows.push(
+            Row::new(vec![
+                Cell::from("Total"),
+                Cell::from(""),
+                Cell::from(format_bytes(total)),
+                Cell::from(format_bytes(available)),
+                Cell::from(Span::styled(
+                    format_percent(used_pct),
+                    theme::gauge_style(used_pct, opts.palette()),
+                )),
+                Cell::from(format_rate(disk.read_rate)),
+                Cell::from(format_rate(disk.write_rate)),
+            ])
+            .style(theme::title_style()),
+        );
+    }
 
-    let widths = [
-        Constraint::Length(16),
-        Constraint::Min(12),
-        Constraint::Length(10),
-        Constraint::Length(10),
-        Constraint::Length(7),
-        Constraint::Length(10),
-        Constraint::Length(10),
+    let column = |title: &str, width: Constraint, field: Option<DiskSortField>| SortableColumn {
+        title: title.to_string(),
+        width,
+        is_sorted: field.is_some() && field == disk.sort_field,
+        ascending: disk.sort_ascending,
+    };
+    let columns = [
+        column("Name", Constraint::Length(16), None),
+        column("Mount", Constraint::Min(12), None),
+        column("Total", Constraint::Length(10), Some(DiskSortField::Size)),
+        column("Avail", Constraint::Length(10), None),
+        column("Used", Constraint::Length(7), Some(DiskSortField::Used)),
+        column("Read/s", Constraint::Length(10), Some(DiskSortField::Io)),
+        column("Write/s", Constraint::Length(10), Some(DiskSortField::Io)),
     ];
 
-    let block = Block::default()
-        .title(Line::styled(" Volumes ", theme::title_style()))
-        .borders(Borders::ALL)
-        .border_style(theme::border_style())
-        .style(Style::default().bg(theme::BASE));
-
-    let table = Table::new(rows, &widths).header(header).block(block);
-    frame.render_widget(table, chunks[0]);
+    sortable_table::render(frame, chunks[0], "Volumes", &columns, rows, None);
 
     // Read I/O sparkline (aggregate)
     let read_max = metrics.disk.read_history.max() as u64;
-    let read_data = metrics.disk.read_history.as_u64_vec(area.width as usize);
-    sparkline_panel::render(
+    sparkline_panel::render_history(
         frame,
         chunks[1],
+        opts,
         "Disk Read (total)",
-        &read_data,
+        &metrics.disk.read_history,
+        Downsample::Max,
         Some(read_max.max(1)),
         theme::TEAL,
-        &format_rate(metrics.disk.read_rate),
+        &with_iops(format_rate(metrics.disk.read_rate), metrics.disk.read_iops),
     );
 
     // Write I/O sparkline (aggregate)
     let write_max = metrics.disk.write_history.max() as u64;
-    let write_data = metrics.disk.write_history.as_u64_vec(area.width as usize);
-    sparkline_panel::render(
+    sparkline_panel::render_history(
         frame,
         chunks[2],
+        opts,
         "Disk Write (total)",
-        &write_data,
+        &metrics.disk.write_history,
+        Downsample::Max,
         Some(write_max.max(1)),
         theme::PEACH,
-        &format_rate(metrics.disk.write_rate),
+        &with_iops(
+            format_rate(metrics.disk.write_rate),
+            metrics.disk.write_iops,
+        ),
     );
 }
+
+/// Append the operation rate to a throughput label when it's known.
+fn with_iops(rate: String, iops: Option<f64>) -> String {
+    match iops {
+        Some(ops) => format!("{rate}  {ops:.0} IOPS"),
+        None => rate,
+    }
+}
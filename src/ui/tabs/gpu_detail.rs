@@ -1,32 +1,69 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Frame;
 
+use crate::metrics::gpu::GpuMemoryKind;
+use crate::metrics::history::Downsample;
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
 use crate::ui::widgets::{metric_gauge, sparkline_panel};
-use crate::util::format_bytes;
+use crate::ui::RenderOptions;
+use crate::util::{format_bytes, format_percent};
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
-    let gpu = &metrics.gpu;
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    zoomed: bool,
+    opts: RenderOptions,
+) {
+    let Some(gpu) = metrics.gpu.device() else {
+        let block = Block::default()
+            .title(Line::styled(" GPU ", theme::title_style()))
+            .borders(Borders::ALL)
+            .border_style(theme::border_style())
+            .style(Style::default().bg(theme::BASE));
+        let msg = Paragraph::new(Line::styled(
+            " No GPU accelerators found in the IORegistry.",
+            theme::label_style(),
+        ))
+        .block(block);
+        frame.render_widget(msg, area);
+        return;
+    };
+    let device_count = metrics.gpu.devices.len();
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // GPU info
-            Constraint::Length(5), // Utilization sparkline
-            Constraint::Length(3), // Renderer gauge
-            Constraint::Length(3), // Tiler gauge
-            Constraint::Length(3), // Memory info
-            Constraint::Min(0),    // spacer
-        ])
+        .constraints(zoom_constraints(
+            [
+                Constraint::Length(3), // GPU info
+                Constraint::Length(5), // Utilization sparkline
+                Constraint::Length(3), // Renderer gauge
+                Constraint::Length(3), // Tiler gauge
+                Constraint::Length(3), // Memory gauge
+                Constraint::Min(0),    // GPU processes
+            ],
+            &[1],
+            zoomed,
+        ))
         .split(area);
 
     // GPU info
+    let title = if device_count > 1 {
+        format!(
+            " GPU {}/{} (j/k to switch) ",
+            metrics.gpu.selected + 1,
+            device_count
+        )
+    } else {
+        " GPU ".to_string()
+    };
     let info_block = Block::default()
-        .title(Line::styled(" GPU ", theme::title_style()))
+        .title(Line::styled(title, theme::title_style()))
         .borders(Borders::ALL)
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
@@ -34,6 +71,8 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     let info_text = Line::from(vec![
         Span::styled(" Model: ", theme::label_style()),
         Span::styled(&gpu.model, theme::value_style()),
+        Span::styled("  Vendor: ", theme::label_style()),
+        Span::styled(gpu.vendor.label(), theme::value_style()),
         Span::styled("  Cores: ", theme::label_style()),
         Span::styled(format!("{}", gpu.core_count), theme::value_style()),
     ]);
@@ -41,12 +80,13 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     frame.render_widget(Paragraph::new(info_text).block(info_block), chunks[0]);
 
     // Device utilization sparkline
-    let util_data = gpu.utilization_history.as_u64_vec(area.width as usize);
-    sparkline_panel::render(
+    sparkline_panel::render_history(
         frame,
         chunks[1],
+        opts,
         "Device Utilization",
-        &util_data,
+        &gpu.utilization_history,
+        Downsample::Average,
         Some(100),
         theme::GREEN,
         &format!("{:.0}%", gpu.device_utilization),
@@ -56,6 +96,7 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     metric_gauge::render(
         frame,
         chunks[2],
+        opts.palette(),
         "Renderer",
         gpu.renderer_utilization,
         &format!("{:.0}%", gpu.renderer_utilization),
@@ -65,24 +106,119 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     metric_gauge::render(
         frame,
         chunks[3],
+        opts.palette(),
         "Tiler",
         gpu.tiler_utilization,
         &format!("{:.0}%", gpu.tiler_utilization),
     );
 
-    // Memory info
-    let mem_block = Block::default()
-        .title(Line::styled(" GPU Memory ", theme::title_style()))
+    // Memory gauge (in use vs what the GPU can address)
+    match gpu.memory_total(metrics.memory.total_ram) {
+        Some((total, kind)) => {
+            let percent = if total > 0 {
+                gpu.in_use_memory as f64 / total as f64 * 100.0
+            } else {
+                0.0
+            };
+            let kind_label = match kind {
+                GpuMemoryKind::Dedicated => "VRAM",
+                GpuMemoryKind::Unified => "unified",
+            };
+            let label = format!(
+                "{} / {} {} ({})  Allocated: {}",
+                format_bytes(gpu.in_use_memory),
+                format_bytes(total),
+                kind_label,
+                format_percent(percent),
+                format_bytes(gpu.alloc_memory),
+            );
+            metric_gauge::render(
+                frame,
+                chunks[4],
+                opts.palette(),
+                "GPU Memory",
+                percent,
+                &label,
+            );
+        }
+        None => {
+            let mem_block = Block::default()
+                .title(Line::styled(" GPU Memory ", theme::title_style()))
+                .borders(Borders::ALL)
+                .border_style(theme::border_style())
+                .style(Style::default().bg(theme::BASE));
+
+            let mem_text = Line::from(vec![
+                Span::styled(" In Use: ", theme::label_style()),
+                Span::styled(format_bytes(gpu.in_use_memory), theme::value_style()),
+                Span::styled("  Allocated: ", theme::label_style()),
+                Span::styled(format_bytes(gpu.alloc_memory), theme::value_style()),
+                Span::styled("  Total: ", theme::label_style()),
+                Span::styled("unknown", theme::label_style()),
+            ]);
+
+            frame.render_widget(Paragraph::new(mem_text).block(mem_block), chunks[4]);
+        }
+    }
+
+    render_processes(frame, chunks[5], metrics, opts);
+}
+
+/// Processes with GPU time since the last sample, busiest first.
+fn render_processes(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    opts: RenderOptions,
+) {
+    if area.height < 3 {
+        return;
+    }
+    let block = Block::default()
+        .title(Line::styled(" GPU Processes ", theme::title_style()))
         .borders(Borders::ALL)
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
 
-    let mem_text = Line::from(vec![
-        Span::styled(" In Use: ", theme::label_style()),
-        Span::styled(format_bytes(gpu.in_use_memory), theme::value_style()),
-        Span::styled("  Allocated: ", theme::label_style()),
-        Span::styled(format_bytes(gpu.alloc_memory), theme::value_style()),
-    ]);
+    let gpu_processes = &metrics.gpu.processes;
+    if gpu_processes.is_empty() {
+        let msg = Paragraph::new(Line::styled(
+            " No per-process GPU time reported (Apple GPUs only).",
+            theme::label_style(),
+        ))
+        .block(block);
+        frame.render_widget(msg, area);
+        return;
+    }
 
-    frame.render_widget(Paragraph::new(mem_text).block(mem_block), chunks[4]);
+    let rows: Vec<Row> = gpu_processes
+        .iter()
+        .map(|gp| {
+            // Prefer the full name from the process list; the ioreg creator
+            // string is truncated
+            let name = metrics
+                .processes
+                .processes
+                .iter()
+                .find(|p| p.pid == gp.pid)
+                .map_or(gp.name.as_str(), |p| p.name.as_str());
+            Row::new(vec![
+                Cell::from(gp.pid.to_string()),
+                Cell::from(name.to_string()),
+                Cell::from(Span::styled(
+                    format_percent(gp.gpu_percent),
+                    theme::gauge_style(gp.gpu_percent, opts.palette()),
+                )),
+            ])
+        })
+        .collect();
+    let header = Row::new(vec!["PID", "Name", "GPU%"])
+        .style(theme::label_style())
+        .height(1);
+    let widths = [
+        Constraint::Length(8),
+        Constraint::Min(20),
+        Constraint::Length(8),
+    ];
+    frame.render_widget(Table::new(rows, widths).header(header).block(block), area);
 }
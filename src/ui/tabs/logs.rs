@@ -1,5 +1,5 @@
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
@@ -7,8 +7,19 @@ use ratatui::Frame;
 use crate::logs::stream::LogStream;
 use crate::logs::LogLevel;
 use crate::ui::theme;
+use crate::ui::widgets::scrollbar;
+use crate::util::format_age;
+use std::time::SystemTime;
 
-pub fn render(frame: &mut Frame, area: Rect, log_stream: &LogStream, scroll_offset: usize) {
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    log_stream: &LogStream,
+    scroll_offset: usize,
+    selected: usize,
+    wrap: bool,
+    hscroll: usize,
+) {
     let filtered = log_stream.filtered_entries();
 
     let filter_info = if let Some(ref level) = log_stream.level_filter {
@@ -24,48 +35,142 @@ pub fn render(frame: &mut Frame, area: Rect, log_stream: &LogStream, scroll_offs
     };
 
     let auto_info = if log_stream.auto_scroll {
-        " [auto-scroll]"
+        " [auto-scroll]".to_string()
+    } else if filtered.is_empty() {
+        String::new()
+    } else {
+        format!(" [line {}]", selected.min(filtered.len() - 1) + 1)
+    };
+
+    let pause_info = if log_stream.error_pending {
+        " [PAUSED on error — any key resumes]"
+    } else if log_stream.pause_on_error {
+        " [pause on error]"
     } else {
         ""
     };
 
+    let wrap_info = match (wrap, hscroll) {
+        (true, _) => String::new(),
+        (false, 0) => " [no wrap]".to_string(),
+        (false, col) => format!(" [no wrap, col {}]", col + 1),
+    };
+
+    let stream_info = if log_stream.error.is_some() {
+        " [stream stopped — r restarts]"
+    } else {
+        ""
+    };
+
+    let buffer_info = if log_stream.is_full() {
+        format!(
+            " [buffer full: {} max, {} dropped]",
+            log_stream.max_entries, log_stream.dropped
+        )
+    } else {
+        String::new()
+    };
+
     let title = format!(
-        "Logs ({}){}{}{}",
+        "Logs ({}){}{}{}{}{}{}{}",
         filtered.len(),
+        stream_info,
+        buffer_info,
         filter_info,
         text_filter_info,
         auto_info,
+        wrap_info,
+        pause_info,
     );
 
+    // Per-level counts across the whole buffer
+    let mut counts = vec![Span::raw(" ")];
+    for level in &LogLevel::ALL {
+        counts.push(Span::styled(
+            format!("{} ", level.as_str()),
+            theme::label_style(),
+        ));
+        counts.push(Span::styled(
+            format!("{}  ", log_stream.level_counts[level.index()]),
+            Style::default().fg(level_color(level)),
+        ));
+    }
+
+    let border_style = if log_stream.error_pending {
+        Style::default().fg(theme::RED)
+    } else {
+        theme::border_style()
+    };
     let block = Block::default()
         .title(Line::styled(format!(" {title} "), theme::title_style()))
+        .title_bottom(Line::from(counts))
         .borders(Borders::ALL)
-        .border_style(theme::border_style())
+        .border_style(border_style)
         .style(Style::default().bg(theme::BASE));
 
+    if let Some(err) = log_stream
+        .error
+        .as_ref()
+        .filter(|_| log_stream.entries.is_empty())
+    {
+        let msg = Paragraph::new(vec![
+            Line::styled(
+                format!(" log stream unavailable: {err}"),
+                Style::default().fg(theme::RED),
+            ),
+            Line::styled(
+                " Reading the unified log may need an admin account; press r to retry.",
+                theme::label_style(),
+            ),
+        ])
+        .block(block)
+        .wrap(Wrap { trim: false });
+        frame.render_widget(msg, area);
+        return;
+    }
+
     let visible_height = area.height.saturating_sub(2) as usize;
+    let inner_width = area.width.saturating_sub(2) as usize;
 
     let effective_offset = if log_stream.auto_scroll {
-        filtered.len().saturating_sub(visible_height)
+        // Back up from the newest entry until the screen is full, counting
+        // continuation lines of multi-line messages
+        let mut start = filtered.len();
+        let mut rows = 0;
+        while start > 0 {
+            let entry_rows = filtered[start - 1].message.lines().count().max(1);
+            if rows + entry_rows > visible_height {
+                break;
+            }
+            rows += entry_rows;
+            start -= 1;
+        }
+        start
     } else {
         scroll_offset.min(filtered.len().saturating_sub(visible_height))
     };
 
+    let now = SystemTime::now();
     let lines: Vec<Line> = filtered
         .iter()
+        .enumerate()
         .skip(effective_offset)
         .take(visible_height)
-        .map(|entry| {
-            let level_color = match entry.level {
-                LogLevel::Error => theme::RED,
-                LogLevel::Fault => theme::RED,
-                LogLevel::Info => theme::GREEN,
-                LogLevel::Debug => theme::MAUVE,
-                LogLevel::Default => theme::SUBTEXT,
+        .flat_map(|(i, entry)| {
+            let level_color = level_color(&entry.level);
+
+            let timestamp = match entry.time {
+                Some(t) if log_stream.relative_time => {
+                    let age = now.duration_since(t).unwrap_or_default();
+                    format!("{:>8}", format_age(age.as_secs()))
+                }
+                _ => entry.timestamp.clone(),
             };
 
-            Line::from(vec![
-                Span::styled(&entry.timestamp, theme::label_style()),
+            let mut message_lines = entry.message.lines();
+            let first = message_lines.next().unwrap_or("");
+            let line = Line::from(vec![
+                Span::styled(timestamp, theme::label_style()),
                 Span::raw(" "),
                 Span::styled(
                     format!("[{}]", entry.level.as_str()),
@@ -74,13 +179,84 @@ pub fn render(frame: &mut Frame, area: Rect, log_stream: &LogStream, scroll_offs
                 Span::raw(" "),
                 Span::styled(&entry.process, Style::default().fg(theme::BLUE)),
                 Span::raw(": "),
-                Span::styled(&entry.message, theme::value_style()),
-            ])
+                Span::styled(first, theme::value_style()),
+            ]);
+            // Continuation lines (stack traces etc.) are indented under the entry
+            let mut lines = vec![line];
+            lines.extend(
+                message_lines.map(|cont| Line::styled(format!("    {cont}"), theme::value_style())),
+            );
+            if !wrap {
+                lines = lines
+                    .into_iter()
+                    .map(|l| clip_line(l, hscroll, inner_width))
+                    .collect();
+            }
+            if !log_stream.auto_scroll && i == selected {
+                lines = lines
+                    .into_iter()
+                    .map(|l| l.style(theme::highlight_style()))
+                    .collect();
+            }
+            lines
         })
         .collect();
 
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .wrap(Wrap { trim: false });
+    let mut paragraph = Paragraph::new(lines).block(block);
+    if wrap {
+        paragraph = paragraph.wrap(Wrap { trim: false });
+    }
     frame.render_widget(paragraph, area);
+    scrollbar::render(
+        frame,
+        area,
+        effective_offset,
+        visible_height,
+        filtered.len(),
+    );
+}
+
+/// Scroll `line` left by `skip` characters and cut it to `width`, marking
+/// either clipped edge with `…`.
+fn clip_line(line: Line<'_>, skip: usize, width: usize) -> Line<'_> {
+    let total: usize = line.spans.iter().map(|s| s.content.chars().count()).sum();
+    if width == 0 || (skip == 0 && total <= width) {
+        return line;
+    }
+    let clipped_left = skip > 0 && total > 0;
+    let clipped_right = total > skip + width;
+    // Leave room for the markers inside the visible width
+    let start = skip + usize::from(clipped_left);
+    let keep = width.saturating_sub(usize::from(clipped_left) + usize::from(clipped_right));
+
+    let mut spans = Vec::new();
+    if clipped_left {
+        spans.push(Span::styled("…", theme::label_style()));
+    }
+    let mut pos = 0;
+    let mut remaining = keep;
+    for span in line.spans {
+        let len = span.content.chars().count();
+        if remaining > 0 && pos + len > start {
+            let from = start.saturating_sub(pos);
+            let text: String = span.content.chars().skip(from).take(remaining).collect();
+            remaining -= text.chars().count();
+            spans.push(Span::styled(text, span.style));
+        }
+        pos += len;
+    }
+    if clipped_right {
+        spans.push(Span::styled("…", theme::label_style()));
+    }
+    Line::from(spans).style(line.style)
+}
+
+fn level_color(level: &LogLevel) -> Color {
+    match level {
+        LogLevel::Error => theme::RED,
+        LogLevel::Fault => theme::RED,
+        LogLevel::Info => theme::GREEN,
+        LogLevel::Debug => theme::MAUVE,
+        LogLevel::Default => theme::SUBTEXT,
+    }
 }
@@ -3,24 +3,121 @@ use ratatui::style::Style;
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
+use std::time::Duration;
 
+use crate::metrics::history::Downsample;
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
+use crate::ui::widgets::line_chart::{self, Series};
 use crate::ui::widgets::{metric_gauge, sparkline_panel};
+use crate::ui::RenderOptions;
 use crate::util::{format_bytes, format_percent};
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
+/// `line_chart` is the sample interval when the tab shows one line chart of
+/// RAM and swap instead of the two sparklines.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    line_chart: Option<Duration>,
+    zoomed: bool,
+    opts: RenderOptions,
+) {
+    if let Some(interval) = line_chart {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(zoom_constraints(
+                [
+                    Constraint::Length(3), // RAM gauge
+                    Constraint::Length(3), // RAM breakdown info
+                    Constraint::Length(3), // Swap gauge
+                    Constraint::Min(8),    // RAM + swap chart
+                ],
+                &[3],
+                zoomed,
+            ))
+            .split(area);
+        render_ram(frame, chunks[0], chunks[1], metrics, opts);
+        render_swap_gauge(frame, chunks[2], metrics, opts);
+        let series = [
+            Series {
+                name: "RAM",
+                history: &metrics.memory.ram_history,
+                color: theme::GREEN,
+            },
+            Series {
+                name: "Swap",
+                history: &metrics.memory.swap_history,
+                color: theme::MAUVE,
+            },
+        ];
+        line_chart::render(
+            frame,
+            chunks[3],
+            "Memory History",
+            &series,
+            100.0,
+            interval,
+            |v| format!("{v:.0}%"),
+        );
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // RAM gauge
-            Constraint::Length(3), // RAM breakdown info
-            Constraint::Length(7), // RAM sparkline
-            Constraint::Length(3), // Swap gauge
-            Constraint::Min(7),    // Swap sparkline
-        ])
+        .constraints(zoom_constraints(
+            [
+                Constraint::Length(3), // RAM gauge
+                Constraint::Length(3), // RAM breakdown info
+                Constraint::Length(7), // RAM sparkline
+                Constraint::Length(3), // Swap gauge
+                Constraint::Min(7),    // Swap sparkline
+            ],
+            &[2],
+            zoomed,
+        ))
         .split(area);
 
+    render_ram(frame, chunks[0], chunks[1], metrics, opts);
+
+    // RAM history sparkline
+    sparkline_panel::render_history(
+        frame,
+        chunks[2],
+        opts,
+        "RAM History",
+        &metrics.memory.ram_history,
+        Downsample::Average,
+        Some(100),
+        theme::GREEN,
+        &format_percent(metrics.memory.ram_percent),
+    );
+
+    render_swap_gauge(frame, chunks[3], metrics, opts);
+
+    // Swap history sparkline
+    sparkline_panel::render_history(
+        frame,
+        chunks[4],
+        opts,
+        "Swap History",
+        &metrics.memory.swap_history,
+        Downsample::Average,
+        Some(100),
+        theme::MAUVE,
+        &format_percent(metrics.memory.swap_percent),
+    );
+}
+
+/// RAM gauge plus the App / Wired / Compressed line under it.
+fn render_ram(
+    frame: &mut Frame,
+    gauge_area: Rect,
+    info_area: Rect,
+    metrics: &MetricsCollector,
+    opts: RenderOptions,
+) {
     // RAM gauge
     let ram_label = format!(
         "{} / {} ({})",
@@ -30,7 +127,8 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     );
     metric_gauge::render(
         frame,
-        chunks[0],
+        gauge_area,
+        opts.palette(),
         "RAM",
         metrics.memory.ram_percent,
         &ram_label,
@@ -48,21 +146,15 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
     let info_para = Paragraph::new(Line::styled(breakdown, theme::value_style())).block(info_block);
-    frame.render_widget(info_para, chunks[1]);
-
-    // RAM history sparkline
-    let ram_data = metrics.memory.ram_history.as_u64_vec(area.width as usize);
-    sparkline_panel::render(
-        frame,
-        chunks[2],
-        "RAM History",
-        &ram_data,
-        Some(100),
-        theme::GREEN,
-        &format_percent(metrics.memory.ram_percent),
-    );
+    frame.render_widget(info_para, info_area);
+}
 
-    // Swap gauge
+fn render_swap_gauge(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    opts: RenderOptions,
+) {
     let swap_label = format!(
         "{} / {} ({})",
         format_bytes(metrics.memory.used_swap),
@@ -71,21 +163,10 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
     );
     metric_gauge::render(
         frame,
-        chunks[3],
+        area,
+        opts.palette(),
         "Swap",
         metrics.memory.swap_percent,
         &swap_label,
     );
-
-    // Swap history sparkline
-    let swap_data = metrics.memory.swap_history.as_u64_vec(area.width as usize);
-    sparkline_panel::render(
-        frame,
-        chunks[4],
-        "Swap History",
-        &swap_data,
-        Some(100),
-        theme::MAUVE,
-        &format_percent(metrics.memory.swap_percent),
-    );
 }
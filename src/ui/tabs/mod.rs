@@ -1,4 +1,6 @@
-#[derive(Clone, Copy, PartialEq, Debug)]
+use ratatui::layout::Constraint;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Tab {
     Dashboard,
     Cpu,
@@ -26,7 +28,7 @@ impl Tab {
         Tab::Temperatures,
     ];
 
-    pub fn label(&self) -> &str {
+    pub fn label(&self) -> &'static str {
         match self {
             Tab::Dashboard => "Dashboard",
             Tab::Cpu => "CPU",
@@ -56,6 +58,30 @@ impl Tab {
         }
     }
 
+    /// Detail tabs whose main panel `z` can zoom to the whole body.
+    pub fn zoomable(&self) -> bool {
+        matches!(
+            self,
+            Tab::Cpu
+                | Tab::Gpu
+                | Tab::Memory
+                | Tab::Disk
+                | Tab::Network
+                | Tab::Processes
+                | Tab::Temperatures
+        )
+    }
+
+    /// Look a tab up by its label, ignoring case ("temperatures" also works).
+    pub fn from_name(name: &str) -> Option<Tab> {
+        if name.eq_ignore_ascii_case("temperatures") {
+            return Some(Tab::Temperatures);
+        }
+        Tab::ALL
+            .into_iter()
+            .find(|t| t.label().eq_ignore_ascii_case(name))
+    }
+
     pub fn from_index(i: usize) -> Option<Tab> {
         Tab::ALL.get(i).copied()
     }
@@ -85,3 +111,22 @@ pub mod memory_detail;
 pub mod network_detail;
 pub mod processes;
 pub mod temperatures;
+
+/// A tab's panel stack, or when `zoomed` just the `keep` panels sharing the
+/// whole body while the others collapse to nothing.
+pub fn zoom_constraints<const N: usize>(
+    constraints: [Constraint; N],
+    keep: &[usize],
+    zoomed: bool,
+) -> [Constraint; N] {
+    if !zoomed {
+        return constraints;
+    }
+    std::array::from_fn(|i| {
+        if keep.contains(&i) {
+            Constraint::Fill(1)
+        } else {
+            Constraint::Length(0)
+        }
+    })
+}
@@ -1,46 +1,48 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
 use ratatui::Frame;
+use std::time::Duration;
 
+use crate::metrics::history::Downsample;
+use crate::metrics::network::{BandwidthTest, Peak};
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
+use crate::ui::widgets::line_chart::{self, Series};
 use crate::ui::widgets::sparkline_panel;
-use crate::util::format_rate;
+use crate::ui::RenderOptions;
+use crate::util::{format_bytes, format_rate, format_uptime, local_clock, now_secs};
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
-    // Connection summary + Total RX/TX sparklines + per-interface sparklines
-    let iface_count = metrics.network.interfaces.len();
-
-    let mut constraints: Vec<Constraint> = vec![
-        Constraint::Length(3), // Connections summary
-        Constraint::Length(6), // Total RX
-        Constraint::Length(6), // Total TX
-    ];
-
-    for _ in 0..iface_count {
-        constraints.push(Constraint::Length(5)); // iface RX
-        constraints.push(Constraint::Length(5)); // iface TX
-    }
-
-    // If not enough space, just show connections + totals
-    let total_sections = 3 + iface_count * 2;
-    if area.height < total_sections as u16 * 4 {
-        constraints = vec![
-            Constraint::Length(3),
-            Constraint::Length(6),
-            Constraint::Min(6),
-        ];
-    }
+/// Samples covered by the rolling-max band behind each sparkline.
+const BAND_WINDOW: usize = 30;
 
+/// `line_chart` is the sample interval when the tab shows one line chart of
+/// total RX and TX instead of the two sparklines.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    line_chart: Option<Duration>,
+    zoomed: bool,
+    opts: RenderOptions,
+) {
+    // Connection summary + Total RX/TX sparklines + selected interface panel
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(&constraints)
+        .constraints(zoom_constraints(
+            [
+                Constraint::Length(3), // Connections summary
+                Constraint::Length(6), // Total RX (or the RX + TX chart)
+                Constraint::Length(6), // Total TX
+                Constraint::Min(8),    // Interface list + details
+            ],
+            &[1, 2],
+            zoomed,
+        ))
         .split(area);
 
-    let width = area.width as usize;
-
     // Connection summary
     let conn = &metrics.network.connections;
     let conn_block = Block::default()
@@ -60,71 +62,289 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
         Span::styled(format!("{}", conn.close_wait), theme::value_style()),
         Span::styled("  Total ", theme::label_style()),
         Span::styled(format!("{}", conn.total()), theme::value_style()),
+        Span::styled("  Session RX ", theme::label_style()),
+        Span::styled(
+            format_bytes(metrics.network.session_rx_bytes),
+            theme::value_style(),
+        ),
+        Span::styled(" TX ", theme::label_style()),
+        Span::styled(
+            format_bytes(metrics.network.session_tx_bytes),
+            theme::value_style(),
+        ),
     ]);
 
-    frame.render_widget(Paragraph::new(conn_line).block(conn_block), chunks[0]);
+    // A running bandwidth test takes over the summary panel
+    if let Some(test) = &metrics.network.bandwidth_test {
+        render_bandwidth_test(frame, chunks[0], test);
+    } else {
+        frame.render_widget(Paragraph::new(conn_line).block(conn_block), chunks[0]);
+    }
 
-    // Total RX
-    let rx_max = metrics.network.total_rx_history.max() as u64;
-    let rx_data = metrics.network.total_rx_history.as_u64_vec(width);
-    if chunks.len() > 1 {
-        sparkline_panel::render(
+    if let Some(interval) = line_chart {
+        let net = &metrics.network;
+        let series = [
+            Series {
+                name: "RX",
+                history: &net.total_rx_history,
+                color: theme::GREEN,
+            },
+            Series {
+                name: "TX",
+                history: &net.total_tx_history,
+                color: theme::BLUE,
+            },
+        ];
+        let title = format!(
+            "Total RX {} / TX {}",
+            format_rate(net.total_rx_rate),
+            format_rate(net.total_tx_rate)
+        );
+        let y_max = net.total_rx_history.max().max(net.total_tx_history.max());
+        let chart_area = chunks[1].union(chunks[2]);
+        line_chart::render(
             frame,
-            chunks[1],
-            "Total RX",
-            &rx_data,
-            Some(rx_max.max(1)),
-            theme::GREEN,
-            &format_rate(metrics.network.total_rx_rate),
+            chart_area,
+            &title,
+            &series,
+            y_max,
+            interval,
+            format_rate,
         );
+        render_interfaces(frame, chunks[3], metrics, opts);
+        return;
     }
 
+    // Total RX
+    let rx_max = metrics.network.total_rx_history.max() as u64;
+    sparkline_panel::render_history_banded(
+        frame,
+        chunks[1],
+        opts,
+        "Total RX",
+        &metrics.network.total_rx_history,
+        Downsample::Max,
+        BAND_WINDOW,
+        Some(rx_max.max(1)),
+        theme::GREEN,
+        &format_rate(metrics.network.total_rx_rate),
+    );
+
     // Total TX
     let tx_max = metrics.network.total_tx_history.max() as u64;
-    let tx_data = metrics.network.total_tx_history.as_u64_vec(width);
-    if chunks.len() > 2 {
-        sparkline_panel::render(
-            frame,
-            chunks[2],
-            "Total TX",
-            &tx_data,
-            Some(tx_max.max(1)),
-            theme::BLUE,
-            &format_rate(metrics.network.total_tx_rate),
-        );
-    }
+    sparkline_panel::render_history_banded(
+        frame,
+        chunks[2],
+        opts,
+        "Total TX",
+        &metrics.network.total_tx_history,
+        Downsample::Max,
+        BAND_WINDOW,
+        Some(tx_max.max(1)),
+        theme::BLUE,
+        &format_rate(metrics.network.total_tx_rate),
+    );
+
+    render_interfaces(frame, chunks[3], metrics, opts);
+}
+
+/// Session peaks of a running bandwidth test and when they happened.
+fn render_bandwidth_test(frame: &mut Frame, area: Rect, test: &BandwidthTest) {
+    let block = Block::default()
+        .title(Line::styled(
+            format!(
+                " Bandwidth Test ({}, T to stop) ",
+                format_uptime(now_secs().saturating_sub(test.started))
+            ),
+            Style::default().fg(theme::YELLOW),
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::YELLOW))
+        .style(Style::default().bg(theme::BASE));
 
-    // Per-interface if space permits
-    let mut chunk_idx = 3;
-    for iface in &metrics.network.interfaces {
-        if chunk_idx + 1 >= chunks.len() {
-            break;
+    let peak = |peak: &Peak| {
+        if peak.at == 0 {
+            "-".to_string()
+        } else {
+            format!("{} at {}", format_rate(peak.rate), local_clock(peak.at))
         }
+    };
+    let line = Line::from(vec![
+        Span::styled(" Peak RX ", theme::label_style()),
+        Span::styled(peak(&test.rx), Style::default().fg(theme::GREEN)),
+        Span::styled("  Peak TX ", theme::label_style()),
+        Span::styled(peak(&test.tx), Style::default().fg(theme::BLUE)),
+    ]);
+    frame.render_widget(Paragraph::new(line).block(block), area);
+}
 
-        let irx_max = iface.rx_history.max() as u64;
-        let irx_data = iface.rx_history.as_u64_vec(width);
-        sparkline_panel::render(
-            frame,
-            chunks[chunk_idx],
-            &format!("{} RX", iface.name),
-            &irx_data,
-            Some(irx_max.max(1)),
-            theme::TEAL,
-            &format_rate(iface.rx_rate),
-        );
+/// Interface list on the left; the selected interface's identity and
+/// sparklines on the right.
+fn render_interfaces(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    opts: RenderOptions,
+) {
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
 
-        let itx_max = iface.tx_history.max() as u64;
-        let itx_data = iface.tx_history.as_u64_vec(width);
-        sparkline_panel::render(
-            frame,
-            chunks[chunk_idx + 1],
-            &format!("{} TX", iface.name),
-            &itx_data,
-            Some(itx_max.max(1)),
-            theme::SKY,
-            &format_rate(iface.tx_rate),
-        );
+    let net = &metrics.network;
+    let visible = net.visible_interfaces();
+    let rows: Vec<Row> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, iface)| {
+            let status_color = if iface.details.is_up {
+                theme::GREEN
+            } else {
+                theme::SURFACE1
+            };
+            let row = Row::new(vec![
+                Cell::from(Span::styled("\u{25cf}", Style::default().fg(status_color))),
+                Cell::from(iface.name.clone()),
+                Cell::from(format_rate(iface.rx_rate)),
+                Cell::from(format_rate(iface.tx_rate)),
+            ]);
+            if i == net.selected_interface {
+                row.style(theme::highlight_style())
+            } else {
+                row
+            }
+        })
+        .collect();
 
-        chunk_idx += 2;
-    }
+    let header = Row::new(vec!["", "Name", "RX", "TX"])
+        .style(theme::label_style())
+        .height(1);
+    let widths = [
+        Constraint::Length(1),
+        Constraint::Min(6),
+        Constraint::Length(11),
+        Constraint::Length(11),
+    ];
+    let list_block = Block::default()
+        .title(Line::styled(
+            if net.show_all {
+                format!(" Interfaces ({}) [all] ", visible.len())
+            } else {
+                format!(
+                    " Interfaces ({} of {}) ",
+                    visible.len(),
+                    net.interfaces.len()
+                )
+            },
+            theme::title_style(),
+        ))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+    // Stateful so the table scrolls to keep the selection in view
+    let mut state = TableState::default().with_selected(Some(net.selected_interface));
+    frame.render_stateful_widget(
+        Table::new(rows, widths).header(header).block(list_block),
+        cols[0],
+        &mut state,
+    );
+
+    let Some(iface) = net.selected() else {
+        return;
+    };
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7), // Details
+            Constraint::Min(3),    // RX
+            Constraint::Min(3),    // TX
+        ])
+        .split(cols[1]);
+
+    let details = &iface.details;
+    let ips = if details.ip_addrs.is_empty() {
+        "-".to_string()
+    } else {
+        details.ip_addrs.join(", ")
+    };
+    let (state, state_color) = if details.is_up {
+        ("up", theme::GREEN)
+    } else {
+        ("down", theme::RED)
+    };
+    // Errors still climbing point at a flaky link
+    let error_style = if iface.errors_rising {
+        Style::default().fg(theme::RED)
+    } else {
+        theme::value_style()
+    };
+    let detail_lines = vec![
+        Line::from(vec![
+            Span::styled(" Status ", theme::label_style()),
+            Span::styled(state, Style::default().fg(state_color)),
+            Span::styled("  MTU ", theme::label_style()),
+            Span::styled(details.mtu.to_string(), theme::value_style()),
+        ]),
+        Line::from(vec![
+            Span::styled(" MAC ", theme::label_style()),
+            Span::styled(details.mac.clone(), theme::value_style()),
+        ]),
+        Line::from(vec![
+            Span::styled(" IP ", theme::label_style()),
+            Span::styled(ips, theme::value_style()),
+        ]),
+        Line::from(vec![
+            Span::styled(" Packets RX ", theme::label_style()),
+            Span::styled(format!("{:.0}/s", iface.rx_packets), theme::value_style()),
+            Span::styled("  TX ", theme::label_style()),
+            Span::styled(format!("{:.0}/s", iface.tx_packets), theme::value_style()),
+            Span::styled("  Errors RX ", theme::label_style()),
+            Span::styled(iface.rx_errors.to_string(), error_style),
+            Span::styled("  TX ", theme::label_style()),
+            Span::styled(iface.tx_errors.to_string(), error_style),
+        ]),
+    ];
+    let detail_block = Block::default()
+        .title(Line::styled(
+            format!(" {} ", iface.name),
+            theme::title_style(),
+        ))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+    frame.render_widget(
+        Paragraph::new(detail_lines)
+            .wrap(Wrap { trim: false })
+            .block(detail_block),
+        right[0],
+    );
+
+    let irx_max = iface.rx_history.max() as u64;
+    sparkline_panel::render_history_banded(
+        frame,
+        right[1],
+        opts,
+        &format!("{} RX", iface.name),
+        &iface.rx_history,
+        Downsample::Max,
+        BAND_WINDOW,
+        Some(irx_max.max(1)),
+        theme::TEAL,
+        &format_rate(iface.rx_rate),
+    );
+
+    let itx_max = iface.tx_history.max() as u64;
+    sparkline_panel::render_history_banded(
+        frame,
+        right[2],
+        opts,
+        &format!("{} TX", iface.name),
+        &iface.tx_history,
+        Downsample::Max,
+        BAND_WINDOW,
+        Some(itx_max.max(1)),
+        theme::SKY,
+        &format_rate(iface.tx_rate),
+    );
 }
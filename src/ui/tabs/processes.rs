@@ -1,64 +1,167 @@
-use ratatui::layout::{Constraint, Rect};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
-use ratatui::text::Span;
-use ratatui::widgets::{Cell, Row};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row};
 use ratatui::Frame;
+use std::collections::VecDeque;
+use std::rc::Rc;
 
-use crate::metrics::process::{ProcessInfo, ProcessSortField};
+use crate::metrics::process::{ProcessColumn, ProcessEvent, ProcessEventKind};
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
 use crate::ui::widgets::sortable_table::{self, SortableColumn};
-use crate::util::format_bytes;
+use crate::ui::widgets::{scrollbar, sparkline_panel};
+use crate::ui::RenderOptions;
+use crate::util::{contains_ignore_ascii_case, format_bytes, local_clock, truncate_str};
 
+/// Leading columns (PID and Name by default) that stay visible while
+/// scrolling horizontally.
+const PINNED_COLUMNS: usize = 2;
+
+/// Height of the process-count sparkline and started/exited feed below
+/// the table.
+pub const BOTTOM_PANEL_HEIGHT: u16 = 6;
+
+/// Largest Left/Right column offset for a table of `column_count` columns;
+/// the last scrollable column always stays in view.
+pub fn max_col_offset(column_count: usize) -> usize {
+    column_count.saturating_sub(PINNED_COLUMNS + 1)
+}
+
+fn column_title(column: ProcessColumn) -> &'static str {
+    match column {
+        ProcessColumn::Pid => "PID",
+        ProcessColumn::Name => "Name",
+        ProcessColumn::User => "User",
+        ProcessColumn::State => "State",
+        ProcessColumn::Cpu => "CPU%",
+        ProcessColumn::Energy => "Energy",
+        ProcessColumn::Memory => "Memory",
+        ProcessColumn::Threads => "Threads",
+        ProcessColumn::Nice => "Nice",
+        ProcessColumn::Cmd => "Command",
+        ProcessColumn::TreeMemory => "Tree Mem",
+    }
+}
+
+fn column_width(column: ProcessColumn) -> Constraint {
+    match column {
+        ProcessColumn::Pid => Constraint::Length(8),
+        ProcessColumn::Name => Constraint::Min(20),
+        ProcessColumn::User => Constraint::Length(12),
+        ProcessColumn::State => Constraint::Length(7),
+        ProcessColumn::Cpu | ProcessColumn::Energy | ProcessColumn::Threads => {
+            Constraint::Length(8)
+        }
+        ProcessColumn::Memory | ProcessColumn::TreeMemory => Constraint::Length(12),
+        ProcessColumn::Nice => Constraint::Length(5),
+        ProcessColumn::Cmd => Constraint::Min(30),
+    }
+}
+
+/// Keep the pinned columns and drop the first `col_offset` scrollable ones.
+fn visible_columns<T>(items: Vec<T>, col_offset: usize) -> Vec<T> {
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| *i < PINNED_COLUMNS || *i >= PINNED_COLUMNS + col_offset)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// The process table and the count sparkline and event feed below it.
+fn split_body(area: Rect, zoomed: bool) -> Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(zoom_constraints(
+            [
+                Constraint::Min(5),                      // Process table
+                Constraint::Length(BOTTOM_PANEL_HEIGHT), // Count trend and events
+            ],
+            &[0],
+            zoomed,
+        ))
+        .split(area)
+}
+
+/// Where each visible column's header is drawn when the tab renders into
+/// `area`, so a click can be mapped back to the column.
+pub fn header_cells(
+    area: Rect,
+    columns: &[ProcessColumn],
+    col_offset: usize,
+    zoomed: bool,
+) -> Vec<(Rect, ProcessColumn)> {
+    let col_offset = col_offset.min(max_col_offset(columns.len()));
+    let visible = visible_columns(columns.to_vec(), col_offset);
+    let widths: Vec<Constraint> = visible.iter().map(|&c| column_width(c)).collect();
+    sortable_table::header_cells(split_body(area, zoomed)[0], &widths)
+        .into_iter()
+        .zip(visible)
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     frame: &mut Frame,
     area: Rect,
     metrics: &MetricsCollector,
     scroll_offset: usize,
     selected: usize,
+    col_offset: usize,
+    followed: bool,
+    zoomed: bool,
+    opts: RenderOptions,
 ) {
     let sort = &metrics.processes;
+    let table_columns = sort.table_columns();
+    let col_offset = col_offset.min(max_col_offset(table_columns.len()));
 
-    let columns = vec![
-        SortableColumn {
-            title: "PID".to_string(),
-            width: Constraint::Length(8),
-            is_sorted: sort.sort_field == ProcessSortField::Pid,
-            ascending: sort.sort_ascending,
-        },
-        SortableColumn {
-            title: "Name".to_string(),
-            width: Constraint::Min(20),
-            is_sorted: sort.sort_field == ProcessSortField::Name,
-            ascending: sort.sort_ascending,
-        },
-        SortableColumn {
-            title: "State".to_string(),
-            width: Constraint::Length(7),
-            is_sorted: false,
-            ascending: false,
-        },
-        SortableColumn {
-            title: "CPU%".to_string(),
-            width: Constraint::Length(8),
-            is_sorted: sort.sort_field == ProcessSortField::Cpu,
-            ascending: sort.sort_ascending,
-        },
-        SortableColumn {
-            title: "Memory".to_string(),
-            width: Constraint::Length(12),
-            is_sorted: sort.sort_field == ProcessSortField::Memory,
-            ascending: sort.sort_ascending,
-        },
-    ];
-
-    let tree_data;
-    let (display_items, total_count): (Vec<&ProcessInfo>, usize) = if sort.tree_mode {
-        tree_data = sort.tree_view();
-        (tree_data.iter().collect(), sort.processes.len())
-    } else {
-        (sort.filtered_processes(), sort.processes.len())
-    };
+    let chunks = split_body(area, zoomed);
+    let area = chunks[0];
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+    render_events(frame, bottom[1], &sort.events, opts);
+
+    // Process count trend (a steady climb hints at a fork bomb or leak)
+    // Plotted above the window's minimum so small changes stay visible
+    let count_data = sort
+        .process_count_history
+        .as_u64_vec(sparkline_panel::history_len(bottom[0].width));
+    let floor = count_data.iter().copied().min().unwrap_or(0);
+    let ceiling = count_data.iter().copied().max().unwrap_or(0);
+    let relative: Vec<u64> = count_data.iter().map(|&c| c - floor + 1).collect();
+    sparkline_panel::render(
+        frame,
+        bottom[0],
+        opts,
+        &format!("Process Count ({floor}–{ceiling})"),
+        &relative,
+        Some(ceiling - floor + 1),
+        theme::PEACH,
+        &format!("{} processes", sort.processes.len()),
+    );
+
+    let columns: Vec<SortableColumn> = table_columns
+        .iter()
+        .map(|&column| {
+            let sort_field = column.sort_field();
+            let is_sorted = sort_field == Some(sort.sort_field);
+            SortableColumn {
+                title: column_title(column).to_string(),
+                width: column_width(column),
+                is_sorted,
+                ascending: is_sorted && sort.sort_ascending,
+            }
+        })
+        .collect();
+    let columns = visible_columns(columns, col_offset);
+
+    let display_items = sort.displayed_processes();
+    let total_count = sort.processes.len();
 
     let visible_rows = area.height.saturating_sub(4) as usize;
     let clamped_offset = scroll_offset.min(display_items.len().saturating_sub(visible_rows));
@@ -67,35 +170,98 @@ pub fn render(
         .skip(clamped_offset)
         .take(visible_rows)
         .map(|p| {
-            let state_color = theme::process_state_color(p.status);
+            let state_color = theme::process_state_color(p.status, opts.palette());
             let name_display = if sort.tree_mode && p.depth > 0 {
                 let indent = "  ".repeat(p.depth.min(8));
                 format!("{indent}{}", p.name)
             } else {
                 p.name.clone()
             };
-            Row::new(vec![
-                Cell::from(format!("{}", p.pid)),
-                Cell::from(name_display),
-                Cell::from(Span::styled(
-                    p.status.label(),
-                    Style::default().fg(state_color),
-                )),
-                Cell::from(format!("{:.1}%", p.cpu_usage)),
-                Cell::from(format_bytes(p.memory)),
-            ])
+            let name_style = if !sort.search_query.is_empty()
+                && contains_ignore_ascii_case(&p.name, &sort.search_query)
+            {
+                Style::default().fg(theme::YELLOW)
+            } else {
+                Style::default()
+            };
+            let cells = table_columns
+                .iter()
+                .map(|column| match column {
+                    ProcessColumn::Pid => Cell::from(format!("{}", p.pid)),
+                    ProcessColumn::Name => {
+                        Cell::from(Span::styled(name_display.clone(), name_style))
+                    }
+                    ProcessColumn::User => Cell::from(sort.username(p.uid).to_string()),
+                    ProcessColumn::State => Cell::from(Span::styled(
+                        p.status.label(),
+                        Style::default().fg(state_color),
+                    )),
+                    ProcessColumn::Cpu => Cell::from(format!("{:.1}%", p.cpu_usage)),
+                    ProcessColumn::Energy => Cell::from(format!("{:.1}", p.energy_impact)),
+                    ProcessColumn::Memory => Cell::from(format_bytes(p.memory)),
+                    ProcessColumn::Threads => Cell::from(format!("{}", p.thread_count)),
+                    ProcessColumn::Nice => Cell::from(format!("{}", p.priority)),
+                    ProcessColumn::Cmd => Cell::from(p.cmd.clone()),
+                    ProcessColumn::TreeMemory => Cell::from(format_bytes(p.subtree_memory)),
+                })
+                .collect();
+            let row = Row::new(visible_columns(cells, col_offset));
+            // Flash rows whose CPU just jumped
+            if sort.spiked.contains(&p.pid) {
+                row.style(Style::default().bg(theme::YELLOW).fg(theme::BASE))
+            } else {
+                row
+            }
         })
         .collect();
 
-    let mode_indicator = if sort.tree_mode { " [tree]" } else { "" };
+    let mode_indicator = match (sort.tree_mode, col_offset > 0) {
+        (true, true) => " [tree] [\u{25c0} cols]",
+        (true, false) => " [tree]",
+        (false, true) => " [\u{25c0} cols]",
+        (false, false) => "",
+    };
+    let search_info = if sort.search_query.is_empty() {
+        String::new()
+    } else {
+        format!(" [find: {} — n/N]", sort.search_query)
+    };
+    let follow_info = format!(
+        "{}{}{}",
+        if sort.hide_system { " [no system]" } else { "" },
+        if sort.only_mine { " [mine]" } else { "" },
+        if followed { " [follow]" } else { "" },
+    );
+    let zombie_info = if sort.zombie_count > 0 {
+        format!(", {} zombie", sort.zombie_count)
+    } else {
+        String::new()
+    };
+    let summary = format!(
+        " — {} running{}, {} total",
+        sort.running_count,
+        zombie_info,
+        format_bytes(sort.total_memory)
+    );
     let title = if sort.filter.is_empty() {
-        format!("Processes ({}){}", display_items.len(), mode_indicator)
+        format!(
+            "Processes ({}){}{}{}{}",
+            display_items.len(),
+            summary,
+            mode_indicator,
+            follow_info,
+            search_info
+        )
     } else {
         format!(
-            "Processes ({}/{}){} [filter: {}]",
+            "Processes ({}/{}){}{}{}{} [{}: {}]",
             display_items.len(),
             total_count,
+            summary,
             mode_indicator,
+            follow_info,
+            search_info,
+            if sort.fuzzy { "fuzzy" } else { "filter" },
             sort.filter
         )
     };
@@ -106,4 +272,55 @@ pub fn render(
         Some(selected.saturating_sub(clamped_offset))
     };
     sortable_table::render(frame, area, &title, &columns, rows, highlight);
+    scrollbar::render(
+        frame,
+        area,
+        clamped_offset,
+        visible_rows,
+        display_items.len(),
+    );
+}
+
+/// The newest started/exited events that fit, newest first.
+fn render_events(
+    frame: &mut Frame,
+    area: Rect,
+    events: &VecDeque<ProcessEvent>,
+    opts: RenderOptions,
+) {
+    let block = Block::default()
+        .title(Line::styled(" Started / Exited ", theme::title_style()))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+    if events.is_empty() {
+        let msg = Paragraph::new(Line::styled(
+            " No processes started or exited yet.",
+            theme::label_style(),
+        ))
+        .block(block);
+        frame.render_widget(msg, area);
+        return;
+    }
+
+    let palette = opts.palette();
+    let name_width = (area.width as usize).saturating_sub(26).max(8);
+    let lines: Vec<Line> = events
+        .iter()
+        .rev()
+        .take(area.height.saturating_sub(2) as usize)
+        .map(|event| {
+            let (mark, color) = match event.kind {
+                ProcessEventKind::Started => ("+", palette.running),
+                ProcessEventKind::Exited => ("-", palette.zombie),
+            };
+            Line::from(vec![
+                Span::styled(format!(" {} ", local_clock(event.at)), theme::label_style()),
+                Span::styled(format!("{mark} "), Style::default().fg(color)),
+                Span::styled(truncate_str(&event.name, name_width), theme::value_style()),
+                Span::styled(format!(" ({})", event.pid), theme::label_style()),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
 }
@@ -1,14 +1,26 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
 use ratatui::text::Line;
-use ratatui::widgets::{BarChart, Block, Borders};
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders};
 use ratatui::Frame;
 
+use crate::metrics::history::Downsample;
+use crate::metrics::temperature::TempUnit;
 use crate::metrics::MetricsCollector;
+use crate::ui::tabs::zoom_constraints;
 use crate::ui::theme;
 use crate::ui::widgets::sparkline_panel;
+use crate::ui::RenderOptions;
+use crate::util::truncate_str;
 
-pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsCollector,
+    temp_unit: TempUnit,
+    zoomed: bool,
+    opts: RenderOptions,
+) {
     if metrics.temperature.sensors.is_empty() {
         let block = Block::default()
             .title(Line::styled(" Temperatures ", theme::title_style()))
@@ -27,29 +39,37 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Min(8),    // Sensor bar chart
-            Constraint::Length(7), // Selected sensor sparkline
-        ])
+        .constraints(zoom_constraints(
+            [
+                Constraint::Min(8),    // Sensor bar chart
+                Constraint::Length(7), // Selected sensor sparkline
+            ],
+            &[1],
+            zoomed,
+        ))
         .split(area);
 
-    // Sensor bar chart
-    let bar_data: Vec<(String, u64)> = metrics
+    // Sensor bar chart, one labeled group per category
+    let sensors = &metrics.temperature.sensors;
+    let groups: Vec<BarGroup> = metrics
         .temperature
-        .sensors
-        .iter()
-        .map(|s| {
-            let label = if s.label.len() > 10 {
-                s.label[..10].to_string()
-            } else {
-                s.label.clone()
-            };
-            (label, s.temperature as u64)
+        .groups()
+        .into_iter()
+        .map(|(category, range)| {
+            let bars: Vec<Bar> = sensors[range]
+                .iter()
+                .map(|s| {
+                    Bar::default()
+                        .label(Line::from(truncate_str(&s.label, 10)))
+                        .value(temp_unit.convert(s.temperature) as u64)
+                })
+                .collect();
+            BarGroup::default()
+                .label(Line::styled(category.label(), theme::title_style()).centered())
+                .bars(&bars)
         })
         .collect();
 
-    let bar_refs: Vec<(&str, u64)> = bar_data.iter().map(|(l, v)| (l.as_str(), *v)).collect();
-
     let max_temp = metrics
         .temperature
         .sensors
@@ -74,28 +94,32 @@ pub fn render(frame: &mut Frame, area: Rect, metrics: &MetricsCollector) {
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
 
-    let barchart = BarChart::default()
+    let mut barchart = BarChart::default()
         .block(bar_block)
-        .data(&bar_refs)
         .bar_width(8)
         .bar_gap(1)
+        .group_gap(3)
         .bar_style(Style::default().fg(theme::PEACH))
         .value_style(Style::default().fg(theme::TEXT))
-        .max(max_temp.max(1));
+        .max((temp_unit.convert(max_temp as f64) as u64).max(1));
+    for group in groups {
+        barchart = barchart.data(group);
+    }
 
     frame.render_widget(barchart, chunks[0]);
 
     // Selected sensor sparkline
     if let Some(sensor) = metrics.temperature.sensors.get(selected) {
-        let data = sensor.history.as_u64_vec(area.width as usize);
-        sparkline_panel::render(
+        sparkline_panel::render_history(
             frame,
             chunks[1],
+            opts,
             &format!("{} History", sensor.label),
-            &data,
+            &sensor.history,
+            Downsample::Average,
             Some(max_temp.max(1)),
             theme::RED,
-            &format!("{:.1} C", sensor.temperature),
+            &temp_unit.format(sensor.temperature),
         );
     }
 }
@@ -15,6 +15,62 @@ pub const MAUVE: Color = Color::Rgb(203, 166, 247);
 pub const TEAL: Color = Color::Rgb(148, 226, 213);
 pub const SKY: Color = Color::Rgb(137, 220, 235);
 
+/// Colors that carry meaning (load levels, process states), so they can be
+/// swapped for a set that doesn't rely on telling red from green.
+pub struct Palette {
+    pub low: Color,
+    pub moderate: Color,
+    pub high: Color,
+    pub critical: Color,
+    pub running: Color,
+    pub stopped: Color,
+    pub zombie: Color,
+}
+
+const DEFAULT_PALETTE: Palette = Palette {
+    low: GREEN,
+    moderate: PEACH,
+    high: YELLOW,
+    critical: RED,
+    running: GREEN,
+    stopped: YELLOW,
+    zombie: RED,
+};
+
+/// Blue/orange/purple, after the Okabe-Ito color-blind safe set.
+const COLORBLIND_PALETTE: Palette = Palette {
+    low: BLUE,
+    moderate: SKY,
+    high: Color::Rgb(230, 159, 0),
+    critical: Color::Rgb(204, 121, 167),
+    running: BLUE,
+    stopped: Color::Rgb(230, 159, 0),
+    zombie: Color::Rgb(204, 121, 167),
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PaletteKind {
+    Default,
+    Colorblind,
+}
+
+impl PaletteKind {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "default" => Some(PaletteKind::Default),
+            "colorblind" => Some(PaletteKind::Colorblind),
+            _ => None,
+        }
+    }
+
+    pub fn colors(self) -> &'static Palette {
+        match self {
+            PaletteKind::Default => &DEFAULT_PALETTE,
+            PaletteKind::Colorblind => &COLORBLIND_PALETTE,
+        }
+    }
+}
+
 pub fn title_style() -> Style {
     Style::default().fg(BLUE).add_modifier(Modifier::BOLD)
 }
@@ -30,6 +86,13 @@ pub fn inactive_tab_style() -> Style {
     Style::default().fg(SUBTEXT).bg(SURFACE0)
 }
 
+pub fn alert_tab_style() -> Style {
+    Style::default()
+        .fg(BASE)
+        .bg(RED)
+        .add_modifier(Modifier::BOLD)
+}
+
 pub fn header_style() -> Style {
     Style::default().fg(TEXT).bg(SURFACE0)
 }
@@ -50,15 +113,15 @@ pub fn value_style() -> Style {
     Style::default().fg(TEXT)
 }
 
-pub fn gauge_style(percent: f64) -> Style {
+pub fn gauge_style(percent: f64, palette: &Palette) -> Style {
     let color = if percent > 90.0 {
-        RED
+        palette.critical
     } else if percent > 70.0 {
-        YELLOW
+        palette.high
     } else if percent > 50.0 {
-        PEACH
+        palette.moderate
     } else {
-        GREEN
+        palette.low
     };
     Style::default().fg(color).bg(BASE)
 }
@@ -71,14 +134,17 @@ pub fn highlight_style() -> Style {
     Style::default().fg(BASE).bg(BLUE)
 }
 
-pub fn process_state_color(state: crate::metrics::process::ProcessState) -> Color {
+pub fn process_state_color(
+    state: crate::metrics::process::ProcessState,
+    palette: &Palette,
+) -> Color {
     use crate::metrics::process::ProcessState;
     match state {
-        ProcessState::Run => GREEN,
+        ProcessState::Run => palette.running,
         ProcessState::Sleep => SUBTEXT,
         ProcessState::Idle => SURFACE1,
-        ProcessState::Zombie => RED,
-        ProcessState::Stop => YELLOW,
+        ProcessState::Zombie => palette.zombie,
+        ProcessState::Stop => palette.stopped,
         ProcessState::Unknown => SURFACE1,
     }
 }
@@ -0,0 +1,48 @@
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+use ratatui::Frame;
+use std::time::{Duration, Instant};
+
+use super::theme;
+use crate::app::{ToastLevel, TOAST_DURATION};
+
+/// Toasts dim for this long before they disappear.
+const FADE: Duration = Duration::from_secs(1);
+
+/// Stack toasts in the bottom-right corner of `area`, newest at the bottom.
+pub fn render(frame: &mut Frame, area: Rect, toasts: &[(String, Instant, ToastLevel)]) {
+    let max_width = area.width / 2;
+    let mut y = area.bottom();
+
+    for (text, created, level) in toasts.iter().rev() {
+        if y <= area.y || max_width < 4 {
+            break;
+        }
+        y -= 1;
+
+        let (icon, color) = match level {
+            ToastLevel::Info => ("✓", theme::GREEN),
+            ToastLevel::Error => ("✗", theme::RED),
+        };
+        let fading = created.elapsed() + FADE >= TOAST_DURATION;
+        let (icon_color, text_color) = if fading {
+            (theme::SURFACE1, theme::SUBTEXT)
+        } else {
+            (color, theme::TEXT)
+        };
+
+        let width = (text.chars().count() as u16 + 5).min(max_width);
+        let rect = Rect::new(area.right() - width, y, width, 1);
+        let line = Line::from(vec![
+            Span::styled(format!(" {icon} "), Style::default().fg(icon_color)),
+            Span::styled(format!("{text} "), Style::default().fg(text_color)),
+        ]);
+        frame.render_widget(Clear, rect);
+        frame.render_widget(
+            Paragraph::new(line).style(Style::default().bg(theme::SURFACE0)),
+            rect,
+        );
+    }
+}
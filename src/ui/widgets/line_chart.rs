@@ -0,0 +1,88 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType};
+use ratatui::Frame;
+use std::time::Duration;
+
+use crate::metrics::history::History;
+use crate::ui::theme;
+use crate::util::format_age;
+
+/// One line on the chart.
+pub struct Series<'a> {
+    pub name: &'a str,
+    pub history: &'a History,
+    pub color: Color,
+}
+
+/// Multi-row line chart of whole histories, newest sample at the right edge.
+/// `interval` is the time between samples, used to label the time axis;
+/// `y_label` formats the value axis (e.g. percent or a byte rate).
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    series: &[Series],
+    y_max: f64,
+    interval: Duration,
+    y_label: impl Fn(f64) -> String,
+) {
+    let points: Vec<Vec<(f64, f64)>> = series.iter().map(|s| s.history.points()).collect();
+    let samples = series
+        .iter()
+        .map(|s| s.history.capacity())
+        .max()
+        .unwrap_or(0)
+        .max(2);
+    let x_min = -((samples - 1) as f64);
+
+    let datasets = series
+        .iter()
+        .zip(&points)
+        .map(|(s, data)| {
+            Dataset::default()
+                .name(s.name)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(s.color))
+                .data(data)
+        })
+        .collect();
+
+    let span_secs = ((samples - 1) as f64 * interval.as_secs_f64()) as u64;
+    let x_labels = vec![
+        Span::styled(format_age(span_secs), theme::label_style()),
+        Span::styled(format_age(span_secs / 2), theme::label_style()),
+        Span::styled("now", theme::label_style()),
+    ];
+    let y_max = y_max.max(1.0);
+    let y_labels = vec![
+        Span::styled(y_label(0.0), theme::label_style()),
+        Span::styled(y_label(y_max / 2.0), theme::label_style()),
+        Span::styled(y_label(y_max), theme::label_style()),
+    ];
+
+    let block = Block::default()
+        .title(Line::styled(format!(" {title} "), theme::title_style()))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style())
+        .style(Style::default().bg(theme::BASE));
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(theme::border_style())
+                .bounds([x_min, 0.0])
+                .labels(x_labels),
+        )
+        .y_axis(
+            Axis::default()
+                .style(theme::border_style())
+                .bounds([0.0, y_max])
+                .labels(y_labels),
+        );
+    frame.render_widget(chart, area);
+}
@@ -1,12 +1,31 @@
 use ratatui::layout::Rect;
 use ratatui::style::Style;
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Gauge};
 use ratatui::Frame;
 
-use crate::ui::theme;
+use crate::ui::theme::{self, Palette};
 
-pub fn render(frame: &mut Frame, area: Rect, title: &str, percent: f64, label: &str) {
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    palette: &Palette,
+    title: &str,
+    percent: f64,
+    label: &str,
+) {
+    render_label(frame, area, palette, title, percent, Span::raw(label));
+}
+
+/// Like `render`, with a styled label (e.g. to flag a warning).
+pub fn render_label(
+    frame: &mut Frame,
+    area: Rect,
+    palette: &Palette,
+    title: &str,
+    percent: f64,
+    label: Span,
+) {
     let clamped = percent.clamp(0.0, 100.0);
 
     let block = Block::default()
@@ -17,7 +36,7 @@ pub fn render(frame: &mut Frame, area: Rect, title: &str, percent: f64, label: &
 
     let gauge = Gauge::default()
         .block(block)
-        .gauge_style(theme::gauge_style(clamped))
+        .gauge_style(theme::gauge_style(clamped, palette))
         .percent(clamped as u16)
         .label(label);
 
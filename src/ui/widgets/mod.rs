@@ -1,3 +1,5 @@
+pub mod line_chart;
 pub mod metric_gauge;
+pub mod scrollbar;
 pub mod sortable_table;
 pub mod sparkline_panel;
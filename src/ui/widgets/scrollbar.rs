@@ -0,0 +1,37 @@
+use ratatui::layout::{Margin, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+use ratatui::Frame;
+
+use crate::ui::theme;
+
+/// Draw a vertical scrollbar over the right border of a bordered panel.
+/// `offset` is the index of the first visible line; nothing is drawn when
+/// everything fits.
+pub fn render(frame: &mut Frame, area: Rect, offset: usize, visible: usize, total: usize) {
+    if total <= visible || visible == 0 {
+        return;
+    }
+
+    // One scroll position per possible offset, so the thumb reaches the
+    // bottom exactly when the last line is visible
+    let positions = total - visible + 1;
+    let mut state = ScrollbarState::new(positions)
+        .position(offset.min(positions - 1))
+        .viewport_content_length(visible);
+
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_style(Style::default().fg(theme::SURFACE1))
+        .thumb_style(Style::default().fg(theme::SUBTEXT));
+
+    frame.render_stateful_widget(
+        scrollbar,
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut state,
+    );
+}
@@ -1,4 +1,4 @@
-use ratatui::layout::{Constraint, Rect};
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::Style;
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Row, Table};
@@ -55,3 +55,18 @@ pub fn render(
         frame.render_widget(table, area);
     }
 }
+
+/// Screen area of each header cell when `render` draws columns of `widths`
+/// into `area`, mirroring the table's own column layout (for mouse clicks).
+pub fn header_cells(area: Rect, widths: &[Constraint]) -> Vec<Rect> {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    let header_row = Rect {
+        height: inner.height.min(1),
+        ..inner
+    };
+    Layout::horizontal(widths.iter().copied())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(header_row)
+        .to_vec()
+}
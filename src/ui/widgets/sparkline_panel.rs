@@ -1,19 +1,128 @@
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
 use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Sparkline};
+use ratatui::widgets::{Block, Borders, Paragraph, Sparkline};
 use ratatui::Frame;
 
-use crate::ui::theme;
+use crate::metrics::history::{Downsample, History};
+use crate::ui::{theme, RenderOptions};
 
+/// Number of samples to request for a panel of `width` columns. Hi-res mode
+/// packs two samples per cell; the panel trims whatever it doesn't need.
+pub fn history_len(width: u16) -> usize {
+    width as usize * 2
+}
+
+/// Number of samples a panel of `width` columns (borders included) draws,
+/// for downsampling a history to exactly fit.
+pub fn sample_count(width: u16, opts: RenderOptions) -> usize {
+    let samples_per_cell = if opts.hi_res { 2 } else { 1 };
+    width.saturating_sub(2) as usize * samples_per_cell
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     frame: &mut Frame,
     area: Rect,
+    opts: RenderOptions,
     title: &str,
     data: &[u64],
     max_val: Option<u64>,
     color: Color,
     annotation: &str,
+) {
+    render_inner(
+        frame, area, opts, title, data, None, max_val, color, annotation,
+    );
+}
+
+/// Render a whole `history`, downsampled to fit per `default` (or its
+/// opposite while swapped). The title names the mode once samples share
+/// columns.
+#[allow(clippy::too_many_arguments)]
+pub fn render_history(
+    frame: &mut Frame,
+    area: Rect,
+    opts: RenderOptions,
+    title: &str,
+    history: &History,
+    default: Downsample,
+    max_val: Option<u64>,
+    color: Color,
+    annotation: &str,
+) {
+    let (title, data, _) = downsampled(area, opts, title, history, default, None);
+    render_inner(
+        frame, area, opts, &title, &data, None, max_val, color, annotation,
+    );
+}
+
+/// Like `render_history`, but shades a faint band behind the sparkline up
+/// to the rolling max over `band_window` samples, so bursty series show
+/// their recent range.
+#[allow(clippy::too_many_arguments)]
+pub fn render_history_banded(
+    frame: &mut Frame,
+    area: Rect,
+    opts: RenderOptions,
+    title: &str,
+    history: &History,
+    default: Downsample,
+    band_window: usize,
+    max_val: Option<u64>,
+    color: Color,
+    annotation: &str,
+) {
+    let (title, data, band) = downsampled(area, opts, title, history, default, Some(band_window));
+    render_inner(
+        frame,
+        area,
+        opts,
+        &title,
+        &data,
+        band.as_deref(),
+        max_val,
+        color,
+        annotation,
+    );
+}
+
+/// The title, samples and optional band for `history` in a panel of `area`.
+fn downsampled(
+    area: Rect,
+    opts: RenderOptions,
+    title: &str,
+    history: &History,
+    default: Downsample,
+    band_window: Option<usize>,
+) -> (String, Vec<u64>, Option<Vec<u64>>) {
+    let mode = if opts.swap_downsample {
+        default.other()
+    } else {
+        default
+    };
+    let count = sample_count(area.width, opts);
+    let data = history.as_u64_vec_downsampled(count, mode);
+    let band = band_window.map(|w| history.rolling_max_downsampled(w, count));
+    let title = if history.len() > count {
+        format!("{title} ({})", mode.label())
+    } else {
+        title.to_string()
+    };
+    (title, data, band)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_inner(
+    frame: &mut Frame,
+    area: Rect,
+    opts: RenderOptions,
+    title: &str,
+    data: &[u64],
+    band: Option<&[u64]>,
+    max_val: Option<u64>,
+    color: Color,
+    annotation: &str,
 ) {
     let block = Block::default()
         .title(Line::styled(format!(" {title} "), theme::title_style()))
@@ -25,11 +134,104 @@ pub fn render(
         .border_style(theme::border_style())
         .style(Style::default().bg(theme::BASE));
 
-    let sparkline = Sparkline::default()
-        .block(block)
-        .data(data)
-        .max(max_val.unwrap_or(100))
-        .style(Style::default().fg(color));
+    let inner = block.inner(area);
+    let inner_width = inner.width as usize;
+    let max = max_val.unwrap_or(100);
+    let samples_per_cell = if opts.hi_res { 2 } else { 1 };
+    let keep = inner_width * samples_per_cell;
+    let data = &data[data.len().saturating_sub(keep)..];
+
+    if opts.hi_res {
+        frame.render_widget(block, area);
+        let lines = braille_lines(data, max, inner.width, inner.height);
+        frame.render_widget(
+            Paragraph::new(lines).style(Style::default().fg(color)),
+            inner,
+        );
+    } else {
+        // Sparkline draws from the left, so keep only the newest samples that fit
+        let sparkline = Sparkline::default()
+            .block(block)
+            .data(data)
+            .max(max)
+            .style(Style::default().fg(color));
+        frame.render_widget(sparkline, area);
+    }
+
+    if let Some(band) = band {
+        let band = &band[band.len().saturating_sub(keep)..];
+        shade_band(frame, inner, data, band, max, samples_per_cell);
+    }
+}
+
+/// Paint a background band in the empty cells between each column's value
+/// and its band height.
+fn shade_band(
+    frame: &mut Frame,
+    inner: Rect,
+    data: &[u64],
+    band: &[u64],
+    max: u64,
+    samples_per_cell: usize,
+) {
+    let max = max.max(1);
+    let height = inner.height as u64;
+    let rows_for = |v: u64| (v.min(max) * height).div_ceil(max);
+    let cell_value = |series: &[u64], col: usize| {
+        series
+            .iter()
+            .skip(col * samples_per_cell)
+            .take(samples_per_cell)
+            .copied()
+            .max()
+    };
+
+    let buf = frame.buffer_mut();
+    for col in 0..inner.width as usize {
+        let (Some(value), Some(band_value)) = (cell_value(data, col), cell_value(band, col)) else {
+            break;
+        };
+        let data_rows = rows_for(value);
+        let band_rows = rows_for(band_value);
+        for r in data_rows..band_rows {
+            let y = inner.y + inner.height - 1 - r as u16;
+            let x = inner.x + col as u16;
+            buf[(x, y)].set_bg(theme::SURFACE0);
+        }
+    }
+}
+
+/// Braille dot bits for the left and right column of a cell, bottom row first.
+const LEFT_DOTS: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+const RIGHT_DOTS: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+
+fn braille_lines(data: &[u64], max: u64, width: u16, height: u16) -> Vec<Line<'static>> {
+    let max = max.max(1);
+    let dot_rows = height as u64 * 4;
+    let levels: Vec<u64> = data
+        .iter()
+        .map(|&v| (v.min(max) * dot_rows).div_ceil(max))
+        .collect();
 
-    frame.render_widget(sparkline, area);
+    (0..height)
+        .map(|row| {
+            // Dot level at the bottom of this text row
+            let row_base = (height - 1 - row) as u64 * 4;
+            let text: String = (0..width as usize)
+                .map(|col| {
+                    let mut bits = 0u32;
+                    for (side, dots) in [LEFT_DOTS, RIGHT_DOTS].iter().enumerate() {
+                        if let Some(&level) = levels.get(col * 2 + side) {
+                            let filled = level.saturating_sub(row_base).min(4) as usize;
+                            for dot in dots.iter().take(filled) {
+                                bits |= dot;
+                            }
+                        }
+                    }
+                    char::from_u32(0x2800 + bits).unwrap_or(' ')
+                })
+                .collect();
+            Line::raw(text)
+        })
+        .collect()
 }
@@ -1,19 +1,71 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How byte sizes and rates are scaled for display.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UnitMode {
+    /// Powers of 1024 (KiB, MiB, GiB), matching what the OS reports
+    Binary,
+    /// Powers of 1000 (KB, MB, GB), matching drive and plan marketing sizes
+    Decimal,
+}
+
+impl UnitMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "binary" => Some(UnitMode::Binary),
+            "decimal" => Some(UnitMode::Decimal),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnitMode::Binary => "binary",
+            UnitMode::Decimal => "decimal",
+        }
+    }
+}
+
+/// Set once from `--units` and flipped at runtime; read by every formatter.
+static DECIMAL_UNITS: AtomicBool = AtomicBool::new(false);
+
+pub fn set_unit_mode(mode: UnitMode) {
+    DECIMAL_UNITS.store(mode == UnitMode::Decimal, Ordering::Relaxed);
+}
+
+pub fn unit_mode() -> UnitMode {
+    if DECIMAL_UNITS.load(Ordering::Relaxed) {
+        UnitMode::Decimal
+    } else {
+        UnitMode::Binary
+    }
+}
+
+/// Step between prefixes and their names (K, M, G, T) for the unit mode.
+fn unit_steps() -> (f64, [&'static str; 4]) {
+    match unit_mode() {
+        UnitMode::Binary => (1024.0, ["KiB", "MiB", "GiB", "TiB"]),
+        UnitMode::Decimal => (1000.0, ["KB", "MB", "GB", "TB"]),
+    }
+}
+
 /// Format bytes into a human-readable string (e.g., "1.23 GiB").
 pub fn format_bytes(bytes: u64) -> String {
-    const KIB: f64 = 1024.0;
-    const MIB: f64 = KIB * 1024.0;
-    const GIB: f64 = MIB * 1024.0;
-    const TIB: f64 = GIB * 1024.0;
+    let (step, [k, m, g, t]) = unit_steps();
+    let kilo = step;
+    let mega = kilo * step;
+    let giga = mega * step;
+    let tera = giga * step;
 
     let b = bytes as f64;
-    if b >= TIB {
-        format!("{:.2} TiB", b / TIB)
-    } else if b >= GIB {
-        format!("{:.2} GiB", b / GIB)
-    } else if b >= MIB {
-        format!("{:.2} MiB", b / MIB)
-    } else if b >= KIB {
-        format!("{:.1} KiB", b / KIB)
+    if b >= tera {
+        format!("{:.2} {t}", b / tera)
+    } else if b >= giga {
+        format!("{:.2} {g}", b / giga)
+    } else if b >= mega {
+        format!("{:.2} {m}", b / mega)
+    } else if b >= kilo {
+        format!("{:.1} {k}", b / kilo)
     } else {
         format!("{bytes} B")
     }
@@ -21,17 +73,18 @@ pub fn format_bytes(bytes: u64) -> String {
 
 /// Format bytes-per-second into a rate string (e.g., "1.23 MiB/s").
 pub fn format_rate(bytes_per_sec: f64) -> String {
-    const KIB: f64 = 1024.0;
-    const MIB: f64 = KIB * 1024.0;
-    const GIB: f64 = MIB * 1024.0;
+    let (step, [k, m, g, _]) = unit_steps();
+    let kilo = step;
+    let mega = kilo * step;
+    let giga = mega * step;
 
     let b = bytes_per_sec.abs();
-    if b >= GIB {
-        format!("{:.2} GiB/s", bytes_per_sec / GIB)
-    } else if b >= MIB {
-        format!("{:.2} MiB/s", bytes_per_sec / MIB)
-    } else if b >= KIB {
-        format!("{:.1} KiB/s", bytes_per_sec / KIB)
+    if b >= giga {
+        format!("{:.2} {g}/s", bytes_per_sec / giga)
+    } else if b >= mega {
+        format!("{:.2} {m}/s", bytes_per_sec / mega)
+    } else if b >= kilo {
+        format!("{:.1} {k}/s", bytes_per_sec / kilo)
     } else {
         format!("{:.0} B/s", bytes_per_sec)
     }
@@ -53,11 +106,119 @@ pub fn format_uptime(secs: u64) -> String {
     }
 }
 
+/// Format an elapsed time as a short relative age (e.g., "12s ago", "3m ago").
+pub fn format_age(secs: u64) -> String {
+    if secs < 60 {
+        format!("{secs}s ago")
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86400)
+    }
+}
+
+/// This machine's hostname, or "unknown".
+pub fn hostname() -> String {
+    hostname::get()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Current time as epoch seconds.
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Epoch seconds broken down in the local time zone.
+fn local_tm(secs: u64) -> libc::tm {
+    unsafe {
+        let t = secs as libc::time_t;
+        let mut tm: libc::tm = std::mem::zeroed();
+        libc::localtime_r(&t, &mut tm);
+        tm
+    }
+}
+
+/// Format epoch seconds as local "YYYYMMDD-HHMMSS" for file names.
+pub fn local_stamp(secs: u64) -> String {
+    let tm = local_tm(secs);
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        tm.tm_year + 1900,
+        tm.tm_mon + 1,
+        tm.tm_mday,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec
+    )
+}
+
+/// Format epoch seconds as local "YYYY-MM-DD HH:MM:SS".
+pub fn local_datetime(secs: u64) -> String {
+    let tm = local_tm(secs);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        tm.tm_year + 1900,
+        tm.tm_mon + 1,
+        tm.tm_mday,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec
+    )
+}
+
+/// Format epoch seconds as local "HH:MM:SS".
+pub fn local_clock(secs: u64) -> String {
+    let tm = local_tm(secs);
+    format!("{:02}:{:02}:{:02}", tm.tm_hour, tm.tm_min, tm.tm_sec)
+}
+
 /// Format a percentage with one decimal (e.g., "45.2%").
 pub fn format_percent(value: f64) -> String {
     format!("{:.1}%", value)
 }
 
+/// Case-insensitive subsequence match: every character of `needle` appears in
+/// `haystack` in order, so "chr" matches "Google Chrome". Consecutive runs and
+/// matches at word starts score higher; `None` means no match.
+pub fn fuzzy_score(needle: &str, haystack: &str) -> Option<i32> {
+    let mut wanted = needle.chars().map(|c| c.to_ascii_lowercase()).peekable();
+    let mut score = 0;
+    let mut prev_match: Option<usize> = None;
+    let mut prev_char: Option<char> = None;
+
+    for (i, c) in haystack.chars().enumerate() {
+        let Some(&want) = wanted.peek() else {
+            break;
+        };
+        if c.to_ascii_lowercase() == want {
+            wanted.next();
+            score += 1;
+            // Reward runs, lightly penalize gaps (capped so long names aren't
+            // punished for a single skip)
+            match prev_match {
+                Some(p) if p + 1 == i => score += 5,
+                Some(p) => score -= (i - p - 1).min(3) as i32,
+                None => score -= i.min(3) as i32,
+            }
+            let word_start = prev_char
+                .is_none_or(|p| !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase()));
+            if word_start {
+                score += 8;
+            }
+            prev_match = Some(i);
+        }
+        prev_char = Some(c);
+    }
+
+    wanted.peek().is_none().then_some(score)
+}
+
 /// Case-insensitive ASCII substring search without allocation.
 /// `needle` should be lowercase for best results.
 pub fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
@@ -73,6 +234,37 @@ pub fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
         .any(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
 }
 
+/// `s` cut to at most `max_chars` characters, ending in `…` when shortened.
+/// Counts chars rather than bytes so multi-byte text can't split mid-char.
+pub fn truncate_str(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+    let mut out: String = s.chars().take(max_chars.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+/// The text `write` produces. Writing to a String can't fail, so its
+/// `fmt::Result` is dropped here instead of at every caller.
+pub fn write_string(write: impl FnOnce(&mut String) -> std::fmt::Result) -> String {
+    let mut out = String::new();
+    let _ = write(&mut out);
+    out
+}
+
+/// Open `url` in the default browser via `open`. Returns true on success.
+pub fn open_url(url: &str) -> bool {
+    use std::process::{Command, Stdio};
+
+    Command::new("open")
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
 /// Copy text to system clipboard via `pbcopy`. Returns true on success.
 pub fn copy_to_clipboard(text: &str) -> bool {
     use std::io::Write;